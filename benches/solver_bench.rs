@@ -0,0 +1,97 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use wordler::dictionary::{Dictionary, EnglishDictionary};
+use wordler::wordle::eval::{evaluate_guess, evaluate_guess_tl};
+use wordler::wordle::solver::{solve_all, solve_one, EntropyStrategy, FrequencyStrategy};
+
+// Used when the system dictionary is not available
+const FALLBACK_WORDS: [&str; 16] = [
+    "ARIEL", "CRANE", "DREAM", "DRINK", "EDGER", "ELITE", "GLIDE", "GREAT", "GREED", "JUMPY",
+    "KELLY", "SLATE", "SISSY", "TREAT", "TRULY", "VIVID",
+];
+
+fn dictionary() -> EnglishDictionary {
+    EnglishDictionary::new().unwrap_or_else(|_| EnglishDictionary::from_slice(&FALLBACK_WORDS))
+}
+
+fn bench_dictionary(c: &mut Criterion) {
+    if EnglishDictionary::new().is_err() {
+        eprintln!("skipping EnglishDictionary::new: system dictionary not available");
+        return;
+    }
+    c.bench_function("EnglishDictionary::new", |b| {
+        b.iter(|| EnglishDictionary::new().unwrap())
+    });
+}
+
+fn bench_evaluate_guess(c: &mut Criterion) {
+    c.bench_function("evaluate_guess", |b| {
+        b.iter(|| evaluate_guess(black_box("GREED"), black_box("EDGER")))
+    });
+
+    c.bench_function("evaluate_guess_tl", |b| {
+        b.iter(|| evaluate_guess_tl(black_box("GREED"), black_box("EDGER")))
+    });
+}
+
+fn bench_evaluate_all(c: &mut Criterion) {
+    let dictionary = dictionary();
+    // every guess against every answer is quadratic, keep it bounded
+    let words: Vec<&str> = dictionary.words().into_iter().take(500).collect();
+
+    c.bench_function("evaluate_all", |b| {
+        b.iter(|| {
+            words
+                .iter()
+                .flat_map(|guess| words.iter().map(|answer| evaluate_guess(guess, answer)))
+                .count()
+        })
+    });
+
+    #[cfg(feature = "parallel")]
+    c.bench_function("evaluate_all_parallel", |b| {
+        b.iter(|| {
+            words
+                .par_iter()
+                .flat_map_iter(|guess| words.iter().map(|answer| evaluate_guess(guess, answer)))
+                .count()
+        })
+    });
+}
+
+fn bench_solve(c: &mut Criterion) {
+    // the entropy strategy is quadratic in the number of remaining words
+    let full = dictionary();
+    let words: Vec<&str> = full.words().into_iter().take(500).collect();
+    let dictionary = EnglishDictionary::from_slice(&words);
+    let answer = dictionary.words()[0];
+
+    c.bench_function("solve_one_entropy", |b| {
+        b.iter(|| solve_one(&dictionary, black_box(answer), &EntropyStrategy).unwrap())
+    });
+
+    c.bench_function("solve_all_frequency", |b| {
+        b.iter(|| solve_all(&dictionary, &FrequencyStrategy))
+    });
+
+    #[cfg(feature = "parallel")]
+    c.bench_function("solve_all_frequency_parallel", |b| {
+        let words = dictionary.words();
+        b.iter(|| {
+            words
+                .par_iter()
+                .filter_map(|answer| solve_one(&dictionary, answer, &FrequencyStrategy).ok())
+                .count()
+        })
+    });
+}
+
+criterion_group!(
+    benches,
+    bench_dictionary,
+    bench_evaluate_guess,
+    bench_evaluate_all,
+    bench_solve
+);
+criterion_main!(benches);
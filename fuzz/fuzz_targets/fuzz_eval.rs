@@ -0,0 +1,36 @@
+//! Check invariants of `evaluate_guess` and that `Wordle::play` agrees with it.
+//!
+//! ```bash no_run
+//! cargo +nightly fuzz run fuzz_eval
+//! ```
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use wordler::wordle::eval::evaluate_guess;
+use wordler::wordle::fuzz::FuzzGame;
+use wordler::wordle::Match;
+
+fuzz_target!(|game: FuzzGame| {
+    let answer = game.answer.as_bytes();
+    for (guess, played) in game.guesses.iter().zip(game.play()) {
+        let matches = evaluate_guess(guess, &game.answer).unwrap();
+        assert_eq!(matches, played, "{} against {}", guess, game.answer);
+
+        let guess = guess.as_bytes();
+        for (i, mch) in matches.iter().enumerate() {
+            // Exact matches are exactly the shared positions.
+            assert_eq!(*mch == Match::ExactLocation, guess[i] == answer[i]);
+        }
+        for letter in guess {
+            let in_guess = guess.iter().filter(|b| *b == letter).count();
+            let in_answer = answer.iter().filter(|b| *b == letter).count();
+            let revealed = guess
+                .iter()
+                .zip(matches)
+                .filter(|(b, mch)| *b == letter && *mch != Match::AbsentInWord)
+                .count();
+            // A letter is revealed as many times as it is in both words.
+            assert_eq!(revealed, in_guess.min(in_answer));
+        }
+    }
+});
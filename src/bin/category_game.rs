@@ -0,0 +1,35 @@
+use std::io::{stdin, stdout};
+use std::process::exit;
+
+use anyhow::{anyhow, Result};
+use wordler::chain_dicts;
+use wordler::dictionary::{available_categories, EnglishDictionary};
+use wordler::wordle::{play_interactive, Wordle};
+
+fn main() -> Result<(), anyhow::Error> {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let category = match args.iter().position(|arg| arg == "--category") {
+        Some(index) => args
+            .get(index + 1)
+            .ok_or_else(|| anyhow!("--category expects one of: {}", categories()))?,
+        None => {
+            eprintln!("usage: wordler-category --category <{}>", categories());
+            exit(2);
+        }
+    };
+
+    // answers come from the category, any word of the dictionary is a valid guess
+    let dictionary = chain_dicts!(
+        EnglishDictionary::new()?.with_category(category)?,
+        EnglishDictionary::new()?,
+    );
+    let mut wordle = Wordle::new(&dictionary)?;
+    let result = play_interactive(&mut wordle, &mut stdin().lock(), &mut stdout())?;
+    // the board is redrawn on a cleared screen, so the category is only shown at the end
+    println!("Category: {}", category);
+    exit(if result.won { 0 } else { 1 })
+}
+
+fn categories() -> String {
+    available_categories().join("|")
+}
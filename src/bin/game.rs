@@ -1,28 +1,118 @@
+use std::io::{stdin, stdout, BufRead, Write};
+use std::path::Path;
 use std::process::exit;
 
 use anyhow::Result;
 use wordler::dictionary::EnglishDictionary;
-use wordler::wordle::{PlayResult, Wordle};
+use wordler::wordle::scoring::score_label;
+use wordler::wordle::session::{GameResult, GameSession};
+use wordler::wordle::variant::{play_game_interactive, Absurdle, Dordle, WordleGame};
+use wordler::wordle::{play_interactive, ColorTheme, Wordle, WordleError};
 
 fn main() -> Result<(), anyhow::Error> {
-    let dictionary = EnglishDictionary::new()?;
-    let mut wordle = Wordle::new(&dictionary);
-    let mut current_guess = String::new();
-    loop {
-        current_guess.clear();
-        println!("Enter your guess [{}/6]", wordle.current_attempt());
-        std::io::stdin().read_line(&mut current_guess)?;
-        let play_result = wordle.play(current_guess.trim());
-        match play_result {
-            Ok(play_result) => {
-                println!("{}", play_result);
-                match play_result {
-                    PlayResult::YouWon(_) => exit(0),
-                    PlayResult::YouLost(_, _) => exit(1),
-                    _ => {}
-                }
-            }
-            Err(e) => println!("{}", e),
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let show_stats = args.iter().any(|arg| arg == "--stats");
+    let dictionary = match args.iter().position(|arg| arg == "--cache-dict") {
+        Some(index) => {
+            let path = args
+                .get(index + 1)
+                .ok_or_else(|| anyhow::anyhow!("--cache-dict expects a path"))?;
+            cached_dictionary(Path::new(path))?
         }
+        None => EnglishDictionary::new()?,
+    };
+    let theme = match std::env::var("WORDLE_THEME") {
+        Ok(theme) => theme.parse()?,
+        Err(_) => ColorTheme::default(),
+    };
+    let result = run(
+        &args,
+        &dictionary,
+        theme,
+        &mut stdin().lock(),
+        &mut stdout(),
+    )?;
+    println!("{}", score_label(result.attempts_used, result.won));
+
+    if show_stats {
+        let mut session = GameSession::new();
+        session.record(result);
+        println!("{}", session);
+    }
+    exit(if result.won { 0 } else { 1 })
+}
+
+/// Play the game selected by `args` until it ends.
+///
+/// Variants go through [play_game_interactive], while a plain [Wordle] keeps
+/// its full board and the time of the game with [play_interactive].
+fn run(
+    args: &[String],
+    dictionary: &EnglishDictionary,
+    theme: ColorTheme,
+    stdin: &mut impl BufRead,
+    stdout: &mut impl Write,
+) -> Result<GameResult, WordleError> {
+    let mut game: Box<dyn WordleGame> = if args.iter().any(|arg| arg == "--absurdle") {
+        Box::new(Absurdle::new(dictionary)?)
+    } else if args.iter().any(|arg| arg == "--dordle") {
+        Box::new(Dordle::new(dictionary))
+    } else {
+        let mut wordle = Wordle::new(dictionary)?.with_color_theme(theme);
+        return play_interactive(&mut wordle, stdin, stdout);
+    };
+    play_game_interactive(game.as_mut(), stdin, stdout, theme)
+}
+
+/// Load the dictionary cached at `path`, creating the cache first if missing.
+fn cached_dictionary(path: &Path) -> Result<EnglishDictionary, anyhow::Error> {
+    if path.exists() {
+        return Ok(EnglishDictionary::load(path)?);
+    }
+    let dictionary = EnglishDictionary::new()?;
+    dictionary.save(path)?;
+    Ok(dictionary)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    #[test]
+    fn test_run_wordle_has_time() {
+        let dictionary = EnglishDictionary::from_slice(&["CRANE"]);
+        let mut output = Vec::new();
+        let result = run(
+            &[],
+            &dictionary,
+            ColorTheme::Monochrome,
+            &mut Cursor::new("crane\n".as_bytes()),
+            &mut output,
+        )
+        .unwrap();
+        assert!(result.won);
+        assert!(result.time.is_some());
+        assert!(result.game_id.is_some());
+        // placeholder rows of the full board
+        assert!(String::from_utf8(output).unwrap().contains(" _ "));
+    }
+
+    #[test]
+    fn test_run_absurdle_attempts() {
+        let dictionary = EnglishDictionary::from_slice(&["CRANE"]);
+        let mut output = Vec::new();
+        let result = run(
+            &["--absurdle".to_string()],
+            &dictionary,
+            ColorTheme::Monochrome,
+            &mut Cursor::new("crane\n".as_bytes()),
+            &mut output,
+        )
+        .unwrap();
+        assert!(result.won);
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.contains("Enter your guess [1]"));
+        assert!(!output.contains("/255"));
     }
 }
@@ -0,0 +1,67 @@
+use std::process::exit;
+
+use anyhow::Result;
+use wordler::dictionary::{Dictionary, EnglishDictionary};
+use wordler::wordle::solver::opening_book::OpeningBookStrategy;
+use wordler::wordle::solver::{solve_one, EntropyStrategy, FrequencyStrategy, SolveStrategy};
+
+fn main() -> Result<(), anyhow::Error> {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.iter().any(|arg| arg == "--benchmark") {
+        // the entropy strategy is too slow to solve every word of a full dictionary,
+        // and would search its first two guesses again for every answer without the book
+        let dictionary = EnglishDictionary::new()?.with_common_only();
+        println!("Solving {} common words", dictionary.word_count());
+        print_distribution(
+            "Entropy",
+            &dictionary,
+            &OpeningBookStrategy::new(EntropyStrategy),
+        );
+        print_distribution("Frequency", &dictionary, &FrequencyStrategy);
+        return Ok(());
+    }
+
+    let answer = match args.first() {
+        Some(answer) => answer,
+        None => {
+            eprintln!("usage: wordler-solver <ANSWER> | --benchmark");
+            exit(2);
+        }
+    };
+    let dictionary = EnglishDictionary::new()?;
+    // the book skips the slow first two entropy guesses over the whole dictionary
+    let stats = solve_one(
+        &dictionary,
+        answer,
+        &OpeningBookStrategy::new(EntropyStrategy),
+    )?;
+    for guess in &stats.guesses {
+        println!("{}", guess);
+    }
+    exit(if stats.won { 0 } else { 1 })
+}
+
+fn print_distribution<S: SolveStrategy + Sync>(
+    name: &str,
+    dictionary: &EnglishDictionary,
+    strategy: &S,
+) {
+    #[cfg(feature = "parallel")]
+    let distribution = wordler::wordle::stats::solve_distribution_par(dictionary, strategy);
+    #[cfg(not(feature = "parallel"))]
+    let distribution = wordler::wordle::stats::solve_distribution(dictionary, strategy);
+
+    let solved: u32 = distribution[..6].iter().sum();
+    let total_attempts: u32 = (1..)
+        .zip(&distribution[..6])
+        .map(|(n, count)| n * count)
+        .sum();
+    println!("{}:", name);
+    for (attempts, count) in distribution[..6].iter().enumerate() {
+        println!("  {}: {}", attempts + 1, count);
+    }
+    println!("  X: {}", distribution[6]);
+    if solved > 0 {
+        println!("  average: {:.3}", total_attempts as f64 / solved as f64);
+    }
+}
@@ -1,58 +1,1736 @@
 //! A [Dictionary] trait and [EnglishDictionary] which uses `/usr/share/dict/words` as source.
 //!
-use anyhow::Result;
+//! [EnglishDictionary::new] reads the words from the first source found, see
+//! [EnglishDictionary::new_from_env]:
+//!
+//! 1. The file at `WORDLE_DICT_PATH` when set, which [EnglishDictionary::from_env] requires.
+//! 2. A custom list at `~/.local/share/wordler/words.txt`.
+//! 3. The system word lists `/usr/share/dict/words`, `/usr/dict/words` and
+//!    `/usr/share/dict/web2`.
+//! 4. The bundled common words with the `embedded` feature.
+//!
+//! Otherwise it fails with [DictionaryError::FileNotFound] listing the paths tried.
+//! The source can be changed without recompiling by setting `WORDLE_DICT_PATH`:
+//!
+//! ```bash no_run
+//! WORDLE_DICT_PATH=/path/to/words wordler
+//! ```
 use indexmap::IndexSet;
-use rand::rngs::ThreadRng;
-use rand::Rng;
-use std::cell::RefCell;
+use rand::seq::IteratorRandom;
+use rand::{Rng, RngCore};
+#[cfg(feature = "categories")]
+use std::collections::BTreeMap;
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+use std::string::FromUtf8Error;
+use std::sync::OnceLock;
+
+/// System word lists, in order of preference, see [EnglishDictionary::new_from_env].
+const DICTIONARY_PATHS: &[&str] = &[
+    "/usr/share/dict/words",
+    "/usr/dict/words",
+    "/usr/share/dict/web2",
+];
+/// Custom word list relative to the home directory.
+const USER_DICTIONARY_PATH: &str = ".local/share/wordler/words.txt";
+const DICTIONARY_PATH_ENV: &str = "WORDLE_DICT_PATH";
+
+/// Common words, most frequent first, see [EnglishDictionary::most_common_words].
+const COMMON_WORDS: &str = include_str!("../assets/common_words.txt");
+
+/// Category names mapped to their lowercase words, see [available_categories].
+#[cfg(feature = "categories")]
+const CATEGORIES: &[u8] = include_bytes!("../assets/categories.json");
 
-const DICTIONARY_PATH: &str = "/usr/share/dict/words";
+#[cfg(feature = "categories")]
+static PARSED_CATEGORIES: OnceLock<BTreeMap<String, Vec<String>>> = OnceLock::new();
+
+/// Attempts of [Dictionary::random_n_words] to draw each word by default.
+const RANDOM_N_WORDS_ATTEMPTS_PER_WORD: usize = 100;
+
+static ENGLISH_DICTIONARY: OnceLock<EnglishDictionary> = OnceLock::new();
 
 /// Dictionary trait for online(not implemented) and offline implementations, and testing support.
 pub trait Dictionary {
     /// Get a random word from the Dictionary.
     fn random_word(&self) -> &str;
+    /// Get a random word from the Dictionary drawn with `rng`, for reproducible games.
+    ///
+    /// Dictionaries which don't support it fall back to [Dictionary::random_word].
+    fn random_word_with_rng(&self, rng: &mut dyn RngCore) -> &str {
+        let _ = rng;
+        self.random_word()
+    }
+    /// Get `n` different random words from the Dictionary, or fewer if it doesn't have
+    /// as many.
+    ///
+    /// The default draws [Dictionary::random_word] until enough different words are
+    /// found, giving up after a bounded number of attempts.
+    fn random_n_words(&self, n: usize) -> Vec<&str> {
+        let mut words = Vec::new();
+        let mut seen = HashSet::new();
+        for _ in 0..n.saturating_mul(RANDOM_N_WORDS_ATTEMPTS_PER_WORD) {
+            if words.len() == n {
+                break;
+            }
+            let word = self.random_word();
+            if seen.insert(word) {
+                words.push(word);
+            }
+        }
+        words
+    }
     /// Check if word is present in the Dictionary.
     fn is_valid_word(&self, word: &str) -> bool;
+    /// All words in the Dictionary.
+    ///
+    /// Dictionaries which cannot list their words (like an online one) return none.
+    fn words(&self) -> Vec<&str> {
+        Vec::new()
+    }
+    /// Number of words in the Dictionary.
+    fn word_count(&self) -> usize {
+        self.words().len()
+    }
+    /// Number of words in the Dictionary, see [Dictionary::word_count].
+    fn len(&self) -> usize {
+        self.word_count()
+    }
+    /// Check if the Dictionary has no words, see [Dictionary::word_count].
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    /// Words other than `word`, in any case, at most `max_edits` letter insertions,
+    /// deletions or substitutions away from it, closest first.
+    ///
+    /// Dictionaries which cannot list their words have no suggestions.
+    fn similar_words(&self, word: &str, max_edits: usize) -> Vec<&str> {
+        closest_words(self.words(), word, max_edits)
+    }
 }
 
 /// Implements [Dictionary] using `/usr/share/dict/words` as source.
 #[derive(Debug)]
 pub struct EnglishDictionary {
     words: IndexSet<String>,
-    rng_refcell: RefCell<ThreadRng>,
+    /// Computed on first use, see [EnglishDictionary::statistics].
+    stats: OnceLock<DictionaryStats>,
+    /// File the words were read from, see [EnglishDictionary::reload].
+    source_path: Option<PathBuf>,
+}
+
+/// Properties of the words of an [EnglishDictionary], see [EnglishDictionary::statistics].
+#[derive(Debug, Clone, PartialEq)]
+pub struct DictionaryStats {
+    /// Number of words.
+    pub word_count: usize,
+    /// Number of distinct letters used by any word.
+    pub unique_letters_used: usize,
+    /// Number of words with no letter appearing more than once.
+    pub no_repeated_letters_count: usize,
+    /// Fraction of words having each letter (`A` to `Z`) at each of the 5 positions.
+    pub per_position_letter_frequency: [[f64; 26]; 5],
+    /// Letter starting the most words, `A` when there are none.
+    pub most_common_starting_letter: char,
+    /// Letter ending the most words, `A` when there are none.
+    pub most_common_ending_letter: char,
+}
+
+/// Errors from creating or searching a [Dictionary].
+#[derive(Debug)]
+pub enum DictionaryError {
+    /// The dictionary source could not be read.
+    Io(std::io::Error),
+    /// The dictionary source is not valid UTF-8.
+    Encoding(FromUtf8Error),
+    /// The environment variable with the dictionary path is not set.
+    PathNotSet(&'static str),
+    /// The pattern is not 5 letters or `?`, see [EnglishDictionary::matching_pattern].
+    InvalidPattern(String),
+    /// No words were given to create the dictionary from.
+    EmptyWordList,
+    /// The dictionary was not read from a file, see [EnglishDictionary::reload].
+    NoReloadSource,
+    /// The category is not one of [available_categories].
+    UnknownCategory(String),
+    /// No dictionary file exists at the paths tried, see [EnglishDictionary::new_from_env].
+    FileNotFound(Vec<PathBuf>),
+    /// The dictionary file has none of its `total_words` words of `length` letters.
+    NoWordsOfLength {
+        /// Number of letters of the words kept.
+        length: usize,
+        /// Number of words in the file, of any length.
+        total_words: usize,
+    },
+}
+
+impl Display for DictionaryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DictionaryError::Io(e) => write!(f, "Failed to load dictionary: {}", e),
+            DictionaryError::Encoding(e) => {
+                write!(f, "Failed to load dictionary, invalid UTF-8: {}", e)
+            }
+            DictionaryError::PathNotSet(var) => write!(
+                f,
+                "Environment variable {} with the dictionary path is not set",
+                var
+            ),
+            DictionaryError::InvalidPattern(pattern) => write!(
+                f,
+                "Invalid pattern {}, expected 5 letters or ? characters",
+                pattern
+            ),
+            DictionaryError::EmptyWordList => write!(f, "Dictionary has no words"),
+            DictionaryError::NoReloadSource => {
+                write!(f, "Dictionary was not read from a file to reload")
+            }
+            DictionaryError::UnknownCategory(category) => {
+                write!(f, "Unknown word category: {}", category)
+            }
+            DictionaryError::FileNotFound(paths) => {
+                let paths: Vec<_> = paths
+                    .iter()
+                    .map(|path| path.display().to_string())
+                    .collect();
+                write!(
+                    f,
+                    "No dictionary found at {}, set {} to the path of a word list \
+                     or enable the embedded feature",
+                    paths.join(", "),
+                    DICTIONARY_PATH_ENV
+                )
+            }
+            DictionaryError::NoWordsOfLength {
+                length,
+                total_words,
+            } => write!(
+                f,
+                "Dictionary has no {} letter words among its {} words",
+                length, total_words
+            ),
+        }
+    }
+}
+
+impl std::error::Error for DictionaryError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            DictionaryError::Io(e) => Some(e),
+            DictionaryError::Encoding(e) => Some(e),
+            DictionaryError::PathNotSet(_)
+            | DictionaryError::InvalidPattern(_)
+            | DictionaryError::EmptyWordList
+            | DictionaryError::NoReloadSource
+            | DictionaryError::UnknownCategory(_)
+            | DictionaryError::FileNotFound(_)
+            | DictionaryError::NoWordsOfLength { .. } => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for DictionaryError {
+    fn from(e: std::io::Error) -> Self {
+        DictionaryError::Io(e)
+    }
+}
+
+impl From<FromUtf8Error> for DictionaryError {
+    fn from(e: FromUtf8Error) -> Self {
+        DictionaryError::Encoding(e)
+    }
 }
 
 impl EnglishDictionary {
     /// Create a new English Dictionary of 5 letter words from
-    /// contents of `/usr/share/dict/words`.
+    /// contents of `/usr/share/dict/words`, or of the file at
+    /// `WORDLE_DICT_PATH` environment variable when set.
     ///
-    /// It stores `thread_rng` to support `random_word` trait method.
+    /// Words containing non-alphabetic characters (apostrophes, hyphens) are skipped,
+    /// and words differing only in case are stored once.
     ///
     /// Falliable method as source file may not exist or the encoding is not utf8.
-    pub fn new() -> Result<EnglishDictionary> {
-        let contents = std::fs::read(DICTIONARY_PATH)?;
-        let contents = String::from_utf8(contents)?;
-        let words: IndexSet<String> = contents
-            .split_whitespace()
-            .filter(|w| w.len() == 5)
-            .map(|w| w.to_string().to_uppercase())
+    /// Same as [EnglishDictionary::new_from_env].
+    pub fn new() -> Result<EnglishDictionary, DictionaryError> {
+        Self::new_from_env()
+    }
+
+    /// Create a new English Dictionary from the first source found, as listed
+    /// in the [module documentation](self).
+    ///
+    /// The file at `WORDLE_DICT_PATH` is read even if it doesn't exist, failing with
+    /// [DictionaryError::Io], while other paths are skipped when missing.
+    pub fn new_from_env() -> Result<EnglishDictionary, DictionaryError> {
+        let home = std::env::var_os("HOME").map(PathBuf::from);
+        Self::resolve(
+            std::env::var_os(DICTIONARY_PATH_ENV).map(PathBuf::from),
+            home.map(|home| home.join(USER_DICTIONARY_PATH)),
+            DICTIONARY_PATHS,
+        )
+    }
+
+    fn resolve(
+        env_path: Option<PathBuf>,
+        user_path: Option<PathBuf>,
+        system_paths: &[&str],
+    ) -> Result<EnglishDictionary, DictionaryError> {
+        if let Some(path) = env_path {
+            return Self::read(path);
+        }
+        let candidates: Vec<PathBuf> = user_path
+            .into_iter()
+            .chain(system_paths.iter().map(PathBuf::from))
             .collect();
+        if let Some(path) = candidates.iter().find(|path| path.is_file()) {
+            return Self::read(path);
+        }
+        if cfg!(feature = "embedded") {
+            let words: Vec<String> = common_words().collect();
+            return Ok(Self::from_words(words.iter().map(String::as_str)));
+        }
+        Err(DictionaryError::FileNotFound(candidates))
+    }
+
+    /// Create a new English Dictionary of 5 letter words from contents
+    /// of the file at `WORDLE_DICT_PATH` environment variable.
+    ///
+    /// Unlike [EnglishDictionary::new] it fails when the variable is not set.
+    pub fn from_env() -> Result<EnglishDictionary, DictionaryError> {
+        let path = std::env::var(DICTIONARY_PATH_ENV)
+            .map_err(|_| DictionaryError::PathNotSet(DICTIONARY_PATH_ENV))?;
+        Self::read(path)
+    }
+
+    fn read(path: impl AsRef<Path>) -> Result<EnglishDictionary, DictionaryError> {
+        let path = path.as_ref();
+        let contents = std::fs::read(path)?;
+        let contents = String::from_utf8(contents)?;
+        let mut words: IndexSet<String> = IndexSet::new();
+        let mut duplicates = 0;
+        let mut total_words = 0;
+        for word in contents.split_whitespace().filter(|w| {
+            total_words += 1;
+            w.len() == 5 && w.bytes().all(|b| b.is_ascii_alphabetic())
+        }) {
+            // "crane" and "Crane" both normalize to "CRANE"
+            if !words.insert(word.to_uppercase()) {
+                duplicates += 1;
+            }
+        }
+
+        if duplicates > 0 {
+            eprintln!(
+                "warning: ignored {} duplicate words (case-insensitive) in {}",
+                duplicates,
+                path.display()
+            );
+        }
+        if words.is_empty() {
+            return Err(DictionaryError::NoWordsOfLength {
+                length: 5,
+                total_words,
+            });
+        }
 
         Ok(EnglishDictionary {
             words,
-            rng_refcell: RefCell::new(rand::thread_rng()),
+            stats: OnceLock::new(),
+            source_path: Some(path.to_path_buf()),
+        })
+    }
+
+    /// Read the words again from [EnglishDictionary::source_path], to pick up changes
+    /// to the file without restarting.
+    ///
+    /// Words are kept as they are if the file cannot be read, and words excluded
+    /// after loading, like with [EnglishDictionary::with_excluded], are back after a reload.
+    /// Fails with [DictionaryError::NoReloadSource] for dictionaries not read from a file.
+    pub fn reload(&mut self) -> Result<(), DictionaryError> {
+        let path = self
+            .source_path
+            .as_ref()
+            .ok_or(DictionaryError::NoReloadSource)?;
+        let reloaded = Self::read(path)?;
+        self.words = reloaded.words;
+        self.stats = OnceLock::new();
+        Ok(())
+    }
+
+    /// Number of words in the dictionary.
+    pub fn len(&self) -> usize {
+        self.words.len()
+    }
+
+    /// Check if the dictionary has no words.
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+
+    /// Iterate over the words in order, like `for word in &dictionary`.
+    ///
+    /// ```
+    /// use wordler::dictionary::EnglishDictionary;
+    ///
+    /// let dictionary = EnglishDictionary::from_slice(&["crane", "slate", "jumpy"]);
+    /// let ending_in_e: Vec<&str> = dictionary.iter().filter(|w| w.ends_with('E')).collect();
+    /// assert_eq!(ending_in_e, vec!["CRANE", "SLATE"]);
+    /// ```
+    pub fn iter(&self) -> Iter<'_> {
+        Iter(self.words.iter())
+    }
+
+    /// File the words were read from, `None` for dictionaries created from memory.
+    pub fn source_path(&self) -> Option<&Path> {
+        self.source_path.as_deref()
+    }
+
+    /// Write the words, one per line, to a file at `path` which [EnglishDictionary::load]
+    /// reads faster than the source the dictionary was created from.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), DictionaryError> {
+        let mut contents = String::with_capacity(self.words.len() * 6);
+        for word in &self.words {
+            contents.push_str(word);
+            contents.push('\n');
+        }
+        std::fs::write(path, contents)?;
+        Ok(())
+    }
+
+    /// Read the words of a file written by [EnglishDictionary::save], as they are
+    /// without filtering them by length.
+    ///
+    /// ```no_run
+    /// use wordler::dictionary::EnglishDictionary;
+    ///
+    /// EnglishDictionary::new().unwrap().save("/tmp/words.cache").unwrap();
+    /// let dictionary = EnglishDictionary::load("/tmp/words.cache").unwrap();
+    /// ```
+    pub fn load(path: impl AsRef<Path>) -> Result<EnglishDictionary, DictionaryError> {
+        let path = path.as_ref();
+        let contents = String::from_utf8(std::fs::read(path)?)?;
+        Ok(EnglishDictionary {
+            words: contents
+                .lines()
+                .filter(|line| !line.is_empty())
+                .map(String::from)
+                .collect(),
+            stats: OnceLock::new(),
+            source_path: Some(path.to_path_buf()),
         })
     }
+
+    /// A shared English Dictionary created with [EnglishDictionary::new] on first use.
+    ///
+    /// Creation is retried on next use if it fails.
+    pub fn global() -> Result<&'static EnglishDictionary, DictionaryError> {
+        if let Some(dictionary) = ENGLISH_DICTIONARY.get() {
+            return Ok(dictionary);
+        }
+        let dictionary = EnglishDictionary::new()?;
+        Ok(ENGLISH_DICTIONARY.get_or_init(|| dictionary))
+    }
+
+    /// Create a Dictionary from the given words, uppercased.
+    ///
+    /// Unlike [EnglishDictionary::new] words are not filtered by length.
+    ///
+    /// ```
+    /// use wordler::dictionary::{Dictionary, EnglishDictionary};
+    ///
+    /// let dictionary = EnglishDictionary::from_slice(&["crane", "slate"]);
+    /// assert!(dictionary.is_valid_word("CRANE"));
+    /// ```
+    pub fn from_slice(words: &[&str]) -> EnglishDictionary {
+        Self::from_words(words.iter().copied())
+    }
+
+    /// Create a Dictionary from the given words, uppercased.
+    ///
+    /// Unlike [EnglishDictionary::new] words are not filtered by length.
+    pub fn from_strings(words: &[String]) -> EnglishDictionary {
+        Self::from_words(words.iter().map(String::as_str))
+    }
+
+    /// Remove `banned` words, in any case, from both answers and valid guesses.
+    ///
+    /// ```
+    /// use wordler::dictionary::{Dictionary, EnglishDictionary};
+    ///
+    /// let dictionary = EnglishDictionary::from_slice(&["CRANE", "SLATE"]).with_excluded(&["crane"]);
+    /// assert!(!dictionary.is_valid_word("CRANE"));
+    /// ```
+    pub fn with_excluded(self, banned: &[&str]) -> Self {
+        let banned: HashSet<String> = banned.iter().map(|w| w.to_uppercase()).collect();
+        self.retain(|w| !banned.contains(w))
+    }
+
+    /// Remove words listed one per line in the file at `path`, see [EnglishDictionary::with_excluded].
+    pub fn exclude_file(self, path: impl AsRef<Path>) -> Result<Self, DictionaryError> {
+        let contents = std::fs::read_to_string(path.as_ref())?;
+        let banned: Vec<&str> = contents
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .collect();
+        Ok(self.with_excluded(&banned))
+    }
+
+    /// The `n` most common words of the dictionary, most common first.
+    ///
+    /// Frequencies come from the list of common 5 letter words bundled with the crate,
+    /// counted in the Google Books Ngram data, words not in it are never returned.
+    pub fn most_common_words(&self, n: usize) -> Vec<&str> {
+        common_words()
+            .filter_map(|word| self.words.get(word.as_str()).map(String::as_str))
+            .take(n)
+            .collect()
+    }
+
+    /// Keep only words in the bundled list of common words, so rare and obscure
+    /// words are neither picked as answer nor accepted as guesses,
+    /// see [EnglishDictionary::most_common_words].
+    pub fn with_common_only(self) -> Self {
+        let common: HashSet<String> = common_words().collect();
+        self.retain(|w| common.contains(w))
+    }
+
+    /// Use the words of this dictionary as valid guesses and `answers` as the pool
+    /// actual answers are picked from.
+    pub fn with_answer_pool(self, answers: EnglishDictionary) -> TwoTierDictionary {
+        TwoTierDictionary {
+            answers,
+            guesses: self,
+        }
+    }
+
+    /// Use the words of this dictionary as valid guesses and its `n` most common
+    /// words as answers, see [EnglishDictionary::most_common_words].
+    pub fn common_with_answers(self, n: usize) -> TwoTierDictionary {
+        let answers = Self::from_slice(&self.most_common_words(n));
+        self.with_answer_pool(answers)
+    }
+
+    /// Read 5 letter words from the file at `system_path` as valid guesses,
+    /// and pick answers only among the common ones, like the NYT Wordle does.
+    ///
+    /// Fails with [DictionaryError::EmptyWordList] when the file has no common word.
+    ///
+    /// ```no_run
+    /// use wordler::dictionary::EnglishDictionary;
+    /// use wordler::wordle::Wordle;
+    ///
+    /// let dictionary = EnglishDictionary::nyt_style("/usr/share/dict/words").unwrap();
+    /// let wordle = Wordle::new(&dictionary).unwrap();
+    /// ```
+    pub fn nyt_style(system_path: impl AsRef<Path>) -> Result<TwoTierDictionary, DictionaryError> {
+        let dictionary = Self::read(system_path)?.common_with_answers(usize::MAX);
+        if dictionary.answers.words.is_empty() {
+            return Err(DictionaryError::EmptyWordList);
+        }
+        Ok(dictionary)
+    }
+
+    /// View of the dictionary restricted to the words of `category`,
+    /// one of [available_categories].
+    ///
+    /// Fails with [DictionaryError::EmptyWordList] when the dictionary has no word of it.
+    ///
+    /// ```
+    /// use wordler::dictionary::{Dictionary, EnglishDictionary};
+    ///
+    /// let dictionary = EnglishDictionary::from_slice(&["TIGER", "CRANE"]);
+    /// let animals = dictionary.with_category("animals").unwrap();
+    /// assert_eq!(animals.words(), vec!["TIGER"]);
+    /// ```
+    #[cfg(feature = "categories")]
+    pub fn with_category(
+        self,
+        category: &str,
+    ) -> Result<FilteredDictionary<EnglishDictionary>, DictionaryError> {
+        let words: HashSet<String> = categories()
+            .get(category)
+            .ok_or_else(|| DictionaryError::UnknownCategory(category.to_string()))?
+            .iter()
+            .map(|word| word.to_uppercase())
+            .filter(|word| self.words.contains(word))
+            .collect();
+        if words.is_empty() {
+            return Err(DictionaryError::EmptyWordList);
+        }
+        Ok(FilteredDictionary::new(self, move |word| {
+            words.contains(word)
+        }))
+    }
+
+    /// Words with no letter appearing more than once,
+    /// which reveal the most letters when used as guesses.
+    pub fn words_without_repeated_letters(&self) -> Vec<&str> {
+        self.words
+            .iter()
+            .map(String::as_str)
+            .filter(|word| !has_repeated_letters(word))
+            .collect()
+    }
+
+    /// Words made of only the given `letters`, in any case.
+    pub fn words_using_only(&self, letters: impl IntoIterator<Item = char>) -> Vec<&str> {
+        let allowed: Vec<char> = letters
+            .into_iter()
+            .map(|ch| ch.to_ascii_uppercase())
+            .collect();
+        self.words
+            .iter()
+            .map(String::as_str)
+            .filter(|word| word.chars().all(|ch| allowed.contains(&ch)))
+            .collect()
+    }
+
+    /// Words matching `pattern` of 5 characters, each a letter, in any case,
+    /// expected at that position or `?` for any letter.
+    ///
+    /// ```
+    /// use wordler::dictionary::EnglishDictionary;
+    ///
+    /// let dictionary = EnglishDictionary::from_slice(&["CRANE", "DREAM", "TREAT"]);
+    /// assert_eq!(dictionary.matching_pattern("?RE??").unwrap(), vec!["DREAM", "TREAT"]);
+    /// assert!(dictionary.matching_pattern("?RE?").is_err());
+    /// ```
+    pub fn matching_pattern(&self, pattern: &str) -> Result<Vec<&str>, DictionaryError> {
+        let pattern = pattern.to_ascii_uppercase();
+        if pattern.len() != 5
+            || !pattern
+                .bytes()
+                .all(|ch| ch == b'?' || ch.is_ascii_alphabetic())
+        {
+            return Err(DictionaryError::InvalidPattern(pattern));
+        }
+        Ok(self
+            .words
+            .iter()
+            .map(String::as_str)
+            .filter(|word| {
+                word.len() == 5
+                    && word
+                        .bytes()
+                        .zip(pattern.bytes())
+                        .all(|(ch, expected)| expected == b'?' || ch == expected)
+            })
+            .collect())
+    }
+
+    /// Words matching the regular expression `re`, against uppercase words.
+    #[cfg(feature = "regex")]
+    pub fn matching_regex(&self, re: &regex::Regex) -> Vec<&str> {
+        self.words
+            .iter()
+            .map(String::as_str)
+            .filter(|word| re.is_match(word))
+            .collect()
+    }
+
+    /// Fraction of words having each letter (`A` to `Z`) at each of the 5 positions.
+    pub fn letter_frequency(&self) -> [[f64; 26]; 5] {
+        positional_frequency(self.words.iter().map(String::as_str))
+    }
+
+    /// Properties of the words of the dictionary, computed on first call.
+    ///
+    /// ```
+    /// use wordler::dictionary::EnglishDictionary;
+    ///
+    /// let dictionary = EnglishDictionary::from_slice(&["GREED", "GLIDE", "EDGER"]);
+    /// let stats = dictionary.statistics();
+    /// assert_eq!(stats.word_count, 3);
+    /// assert_eq!(stats.unique_letters_used, 6);
+    /// assert_eq!(stats.no_repeated_letters_count, 1);
+    /// assert_eq!(stats.most_common_starting_letter, 'G');
+    /// assert_eq!(stats.per_position_letter_frequency[0][(b'G' - b'A') as usize], 2.0 / 3.0);
+    /// ```
+    pub fn statistics(&self) -> &DictionaryStats {
+        self.stats.get_or_init(|| {
+            let frequency = self.letter_frequency();
+            let most_common_at = |position: usize| {
+                // first letter wins ties
+                let index = (0..26).fold(0, |best, index| {
+                    if frequency[position][index] > frequency[position][best] {
+                        index
+                    } else {
+                        best
+                    }
+                });
+                (b'A' + index as u8) as char
+            };
+            let letters: HashSet<char> = self.words.iter().flat_map(|w| w.chars()).collect();
+            DictionaryStats {
+                word_count: self.words.len(),
+                unique_letters_used: letters.len(),
+                no_repeated_letters_count: self.words_without_repeated_letters().len(),
+                per_position_letter_frequency: frequency,
+                most_common_starting_letter: most_common_at(0),
+                most_common_ending_letter: most_common_at(4),
+            }
+        })
+    }
+
+    /// The `n` words hardest to discover, hardest first, see [word_difficulty].
+    pub fn hardest_words(&self, n: usize) -> Vec<&str> {
+        let mut words = rank_by_ease(self.words.iter().map(String::as_str).collect());
+        words.reverse();
+        words.truncate(n);
+        words
+    }
+
+    /// The `n` words easiest to discover, easiest first, see [word_difficulty].
+    pub fn easiest_words(&self, n: usize) -> Vec<&str> {
+        let mut words = rank_by_ease(self.words.iter().map(String::as_str).collect());
+        words.truncate(n);
+        words
+    }
+
+    /// The word at index `n` in the order words were loaded, inverse of [EnglishDictionary::index_of].
+    ///
+    /// ```
+    /// use wordler::dictionary::EnglishDictionary;
+    ///
+    /// let dictionary = EnglishDictionary::from_slice(&["CRANE", "SLATE"]);
+    /// assert_eq!(dictionary.word_at_index(1), Some("SLATE"));
+    /// assert_eq!(dictionary.word_at_index(2), None);
+    /// ```
+    pub fn word_at_index(&self, n: usize) -> Option<&str> {
+        self.words.get_index(n).map(String::as_str)
+    }
+
+    /// Index of `word`, in any case, see [EnglishDictionary::word_at_index].
+    pub fn index_of(&self, word: &str) -> Option<usize> {
+        self.words.get_index_of(word.to_uppercase().as_str())
+    }
+
+    /// Get a random word drawn with `rng`.
+    ///
+    /// Panics if the dictionary is empty, which dictionaries read from a file
+    /// never are, see [DictionaryError::NoWordsOfLength].
+    ///
+    /// ```
+    /// use rand::{rngs::StdRng, SeedableRng};
+    /// use wordler::dictionary::EnglishDictionary;
+    ///
+    /// let dictionary = EnglishDictionary::from_slice(&["CRANE", "SLATE", "TRACE"]);
+    /// assert_eq!(
+    ///     dictionary.random_word_with_rng(&mut StdRng::seed_from_u64(7)),
+    ///     dictionary.random_word_with_rng(&mut StdRng::seed_from_u64(7))
+    /// );
+    /// ```
+    pub fn random_word_with_rng(&self, rng: &mut impl Rng) -> &str {
+        debug_assert!(!self.words.is_empty(), "random word of an empty dictionary");
+        let random_index = rng.gen_range(0..self.words.len());
+        self.words.get_index(random_index).unwrap().as_str()
+    }
+
+    fn from_words<'a>(words: impl Iterator<Item = &'a str>) -> EnglishDictionary {
+        EnglishDictionary {
+            words: words.map(|w| w.to_uppercase()).collect(),
+            stats: OnceLock::new(),
+            source_path: None,
+        }
+    }
+
+    fn retain(mut self, keep: impl FnMut(&String) -> bool) -> Self {
+        self.words.retain(keep);
+        self.stats = OnceLock::new();
+        self
+    }
+}
+
+/// Uppercase words of [COMMON_WORDS], most frequent first.
+fn common_words() -> impl Iterator<Item = String> {
+    COMMON_WORDS
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(str::to_uppercase)
+}
+
+/// Names of the bundled word categories, sorted alphabetically,
+/// see [EnglishDictionary::with_category].
+#[cfg(feature = "categories")]
+pub fn available_categories() -> Vec<&'static str> {
+    categories().keys().map(String::as_str).collect()
+}
+
+/// [CATEGORIES] parsed on first use.
+#[cfg(feature = "categories")]
+fn categories() -> &'static BTreeMap<String, Vec<String>> {
+    PARSED_CATEGORIES.get_or_init(|| {
+        serde_json::from_slice(CATEGORIES).expect("bundled categories.json is valid")
+    })
+}
+
+/// Words of `candidates` other than `word` within `max_edits` of it, closest first.
+fn closest_words<'a>(
+    candidates: impl IntoIterator<Item = &'a str>,
+    word: &str,
+    max_edits: usize,
+) -> Vec<&'a str> {
+    let word = word.to_uppercase();
+    let mut similar: Vec<(usize, &str)> = candidates
+        .into_iter()
+        .filter(|candidate| !candidate.eq_ignore_ascii_case(&word))
+        .map(|candidate| (levenshtein(&candidate.to_uppercase(), &word), candidate))
+        .filter(|(distance, _)| *distance <= max_edits)
+        .collect();
+    similar.sort_by_key(|(distance, _)| *distance);
+    similar
+        .into_iter()
+        .map(|(_, candidate)| candidate)
+        .collect()
+}
+
+/// Minimum number of single character insertions, deletions or substitutions
+/// to change `a` into `b`.
+fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+/// Bit set of the ASCII letters of `word`, ignoring case.
+fn letter_set(word: &str) -> u32 {
+    word.bytes()
+        .filter(u8::is_ascii_alphabetic)
+        .fold(0, |set, ch| set | 1 << (ch.to_ascii_uppercase() - b'A'))
+}
+
+/// Check if any letter appears more than once in `word`, ignoring case.
+pub fn has_repeated_letters(word: &str) -> bool {
+    let mut seen = HashSet::new();
+    !word.chars().all(|ch| seen.insert(ch.to_ascii_uppercase()))
+}
+
+/// Score how hard `word` is to discover, from 0.0 (easy) to 1.0 (hard).
+///
+/// Words made of letters common at their position in `dictionary` get revealed
+/// by most guesses, so the score is the inverse of the sum of the letter-position
+/// frequencies of `word`, relative to the easiest word in `dictionary`.
+pub fn word_difficulty(word: &str, dictionary: &EnglishDictionary) -> f64 {
+    let frequency = dictionary.letter_frequency();
+    let easiest = dictionary
+        .words
+        .iter()
+        .map(|w| positional_score(w, &frequency))
+        .fold(0.0, f64::max);
+    if easiest == 0.0 {
+        return 1.0;
+    }
+    (1.0 - positional_score(word, &frequency) / easiest).clamp(0.0, 1.0)
+}
+
+pub(crate) fn positional_frequency<'a>(words: impl IntoIterator<Item = &'a str>) -> [[f64; 26]; 5] {
+    let mut frequency = [[0.0; 26]; 5];
+    let mut count = 0;
+    for word in words {
+        count += 1;
+        for (position, ch) in word.bytes().take(5).enumerate() {
+            if ch.is_ascii_alphabetic() {
+                frequency[position][(ch.to_ascii_uppercase() - b'A') as usize] += 1.0;
+            }
+        }
+    }
+    if count > 0 {
+        for letter_frequency in frequency.iter_mut().flatten() {
+            *letter_frequency /= count as f64;
+        }
+    }
+    frequency
+}
+
+fn positional_score(word: &str, frequency: &[[f64; 26]; 5]) -> f64 {
+    word.bytes()
+        .take(5)
+        .enumerate()
+        .filter(|(_, ch)| ch.is_ascii_alphabetic())
+        .map(|(position, ch)| frequency[position][(ch.to_ascii_uppercase() - b'A') as usize])
+        .sum()
+}
+
+/// Sort `words` from easiest to hardest to discover among themselves.
+pub(crate) fn rank_by_ease(mut words: Vec<&str>) -> Vec<&str> {
+    let frequency = positional_frequency(words.iter().copied());
+    words.sort_by(|a, b| {
+        positional_score(b, &frequency).total_cmp(&positional_score(a, &frequency))
+    });
+    words
+}
+
+impl std::ops::BitAnd for EnglishDictionary {
+    type Output = EnglishDictionary;
+
+    /// Words in both dictionaries, in the order of `self`.
+    ///
+    /// ```
+    /// use wordler::dictionary::{Dictionary, EnglishDictionary};
+    ///
+    /// let british = EnglishDictionary::from_slice(&["COLOUR", "CRANE"]);
+    /// let american = EnglishDictionary::from_slice(&["COLOR", "CRANE"]);
+    /// assert_eq!((british & american).words(), vec!["CRANE"]);
+    /// ```
+    fn bitand(self, other: EnglishDictionary) -> EnglishDictionary {
+        self.retain(|w| other.words.contains(w))
+    }
+}
+
+impl std::ops::BitOr for EnglishDictionary {
+    type Output = EnglishDictionary;
+
+    /// Words in either dictionary, those of `self` first.
+    fn bitor(mut self, other: EnglishDictionary) -> EnglishDictionary {
+        self.words.extend(other.words);
+        self.stats = OnceLock::new();
+        self
+    }
+}
+
+impl std::ops::Sub for EnglishDictionary {
+    type Output = EnglishDictionary;
+
+    /// Words of `self` not in `other`.
+    fn sub(self, other: EnglishDictionary) -> EnglishDictionary {
+        self.retain(|w| !other.words.contains(w))
+    }
+}
+
+impl<'a> IntoIterator for &'a EnglishDictionary {
+    type Item = &'a str;
+    type IntoIter = Iter<'a>;
+
+    fn into_iter(self) -> Iter<'a> {
+        self.iter()
+    }
+}
+
+/// Iterator over the words of an [EnglishDictionary], see [EnglishDictionary::iter].
+#[derive(Debug, Clone)]
+pub struct Iter<'a>(indexmap::set::Iter<'a, String>);
+
+impl<'a> Iterator for Iter<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        self.0.next().map(String::as_str)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl ExactSizeIterator for Iter<'_> {}
+
+impl TryFrom<&[&str]> for EnglishDictionary {
+    type Error = DictionaryError;
+
+    /// Create a Dictionary from the given words like [EnglishDictionary::from_slice],
+    /// failing when there are none.
+    fn try_from(words: &[&str]) -> Result<Self, Self::Error> {
+        if words.is_empty() {
+            return Err(DictionaryError::EmptyWordList);
+        }
+        Ok(Self::from_slice(words))
+    }
+}
+
+impl TryFrom<&str> for EnglishDictionary {
+    type Error = DictionaryError;
+
+    /// Create a Dictionary from words listed one per line, failing when there are none.
+    ///
+    /// ```
+    /// use wordler::dictionary::{Dictionary, EnglishDictionary};
+    ///
+    /// let dictionary = EnglishDictionary::try_from("crane\nslate\n").unwrap();
+    /// assert_eq!(dictionary.words(), vec!["CRANE", "SLATE"]);
+    /// ```
+    fn try_from(words: &str) -> Result<Self, Self::Error> {
+        let words: Vec<&str> = words
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .collect();
+        Self::try_from(words.as_slice())
+    }
 }
 
 impl Dictionary for EnglishDictionary {
     fn random_word(&self) -> &str {
-        let random_index = self.rng_refcell.borrow_mut().gen_range(0..self.words.len());
-        self.words.get_index(random_index).unwrap().as_str()
+        EnglishDictionary::random_word_with_rng(self, &mut rand::thread_rng())
+    }
+
+    fn random_word_with_rng(&self, mut rng: &mut dyn RngCore) -> &str {
+        EnglishDictionary::random_word_with_rng(self, &mut rng)
+    }
+
+    /// Sample `n` words in a single pass over the words, in no particular order.
+    fn random_n_words(&self, n: usize) -> Vec<&str> {
+        self.words
+            .iter()
+            .map(String::as_str)
+            .choose_multiple(&mut rand::thread_rng(), n)
+    }
+
+    fn is_valid_word(&self, word: &str) -> bool {
+        self.words.contains(word)
+    }
+
+    fn words(&self) -> Vec<&str> {
+        self.words.iter().map(String::as_str).collect()
+    }
+
+    fn word_count(&self) -> usize {
+        self.words.len()
+    }
+
+    /// Like the default, skipping words too different in length or sharing no letter
+    /// before computing the edit distance.
+    fn similar_words(&self, word: &str, max_edits: usize) -> Vec<&str> {
+        let word = word.to_uppercase();
+        let letters = letter_set(&word);
+        let candidates = self.words.iter().map(String::as_str).filter(|candidate| {
+            candidate.len().abs_diff(word.len()) <= max_edits
+                // replacing every letter is needed when none is shared
+                && (letter_set(candidate) & letters != 0
+                    || max_edits >= candidate.len().max(word.len()))
+        });
+        closest_words(candidates, &word, max_edits)
+    }
+}
+
+/// A [Dictionary] composed of other dictionaries, accepting a word if any of them does.
+///
+/// Random words come from the first dictionary, so it is the answer pool
+/// while the following ones only extend the valid guesses.
+pub struct ChainedDictionary {
+    dicts: Vec<Box<dyn Dictionary>>,
+}
+
+impl ChainedDictionary {
+    /// Create a ChainedDictionary from `dicts`, which must not be empty.
+    pub fn new(dicts: Vec<Box<dyn Dictionary>>) -> Self {
+        assert!(
+            !dicts.is_empty(),
+            "ChainedDictionary needs at least one dictionary"
+        );
+        ChainedDictionary { dicts }
+    }
+}
+
+impl Dictionary for ChainedDictionary {
+    fn random_word(&self) -> &str {
+        self.dicts[0].random_word()
+    }
+
+    fn is_valid_word(&self, word: &str) -> bool {
+        self.dicts.iter().any(|dict| dict.is_valid_word(word))
+    }
+
+    fn words(&self) -> Vec<&str> {
+        let mut seen = HashSet::new();
+        self.dicts
+            .iter()
+            .flat_map(|dict| dict.words())
+            .filter(|word| seen.insert(*word))
+            .collect()
+    }
+
+    /// Sum of the word counts of all dictionaries,
+    /// words present in several of them are counted more than once.
+    fn word_count(&self) -> usize {
+        self.dicts.iter().map(|dict| dict.word_count()).sum()
+    }
+}
+
+/// A [Dictionary] with separate pools of answers and valid guesses,
+/// see [EnglishDictionary::with_answer_pool].
+///
+/// Answers are always accepted as guesses, even when missing from the valid guesses.
+///
+/// ```
+/// use wordler::dictionary::{Dictionary, EnglishDictionary};
+///
+/// let dictionary = EnglishDictionary::from_slice(&["CRANE", "XYLYL"])
+///     .with_answer_pool(EnglishDictionary::from_slice(&["CRANE"]));
+/// assert_eq!(dictionary.random_word(), "CRANE");
+/// assert!(dictionary.is_valid_word("XYLYL"));
+/// ```
+#[derive(Debug)]
+pub struct TwoTierDictionary {
+    answers: EnglishDictionary,
+    guesses: EnglishDictionary,
+}
+
+impl TwoTierDictionary {
+    /// Words actual answers are picked from.
+    pub fn answers(&self) -> &EnglishDictionary {
+        &self.answers
+    }
+
+    /// Words accepted as guesses, besides the answers.
+    pub fn valid_guesses(&self) -> &EnglishDictionary {
+        &self.guesses
+    }
+}
+
+impl Dictionary for TwoTierDictionary {
+    fn random_word(&self) -> &str {
+        self.answers.random_word()
+    }
+
+    fn random_word_with_rng(&self, rng: &mut dyn RngCore) -> &str {
+        Dictionary::random_word_with_rng(&self.answers, rng)
+    }
+
+    fn random_n_words(&self, n: usize) -> Vec<&str> {
+        self.answers.random_n_words(n)
     }
 
     fn is_valid_word(&self, word: &str) -> bool {
-        matches!(self.words.get(word), Some(_))
+        self.guesses.is_valid_word(word) || self.answers.is_valid_word(word)
+    }
+
+    /// Valid guesses followed by the answers missing from them.
+    fn words(&self) -> Vec<&str> {
+        let mut words = self.guesses.words();
+        words.extend(
+            self.answers
+                .words
+                .iter()
+                .map(String::as_str)
+                .filter(|word| !self.guesses.words.contains(*word)),
+        );
+        words
+    }
+}
+
+/// Attempts of [FilteredDictionary::random_word] to find a word matching its predicate.
+const FILTERED_RANDOM_WORD_ATTEMPTS: usize = 10_000;
+
+/// A view of a [Dictionary] restricted to the words matching a predicate.
+///
+/// ```
+/// use wordler::dictionary::{Dictionary, EnglishDictionary, FilteredDictionary};
+///
+/// let dictionary = EnglishDictionary::from_slice(&["CRANE", "SLATE"]);
+/// let dictionary = FilteredDictionary::new(dictionary, |w| w.starts_with('S'));
+/// assert_eq!(dictionary.random_word(), "SLATE");
+/// ```
+pub struct FilteredDictionary<D: Dictionary> {
+    inner: D,
+    predicate: Box<dyn Fn(&str) -> bool + Send + Sync>,
+}
+
+impl<D: Dictionary> FilteredDictionary<D> {
+    /// Create a FilteredDictionary of the words of `inner` matching `predicate`.
+    pub fn new(inner: D, predicate: impl Fn(&str) -> bool + Send + Sync + 'static) -> Self {
+        FilteredDictionary {
+            inner,
+            predicate: Box::new(predicate),
+        }
+    }
+
+    /// The unfiltered Dictionary.
+    pub fn inner(&self) -> &D {
+        &self.inner
+    }
+}
+
+impl<D: Dictionary> Dictionary for FilteredDictionary<D> {
+    /// Pick a random word among the matching words of the inner Dictionary.
+    ///
+    /// When the inner Dictionary cannot list its words, draw random words from it
+    /// until one matches the predicate, panicking when none is found after a bounded
+    /// number of attempts.
+    fn random_word(&self) -> &str {
+        if let Some(word) = self.words().into_iter().choose(&mut rand::thread_rng()) {
+            return word;
+        }
+        for _ in 0..FILTERED_RANDOM_WORD_ATTEMPTS {
+            let word = self.inner.random_word();
+            if (self.predicate)(word) {
+                return word;
+            }
+        }
+        panic!(
+            "No word matching the predicate found in {} attempts.",
+            FILTERED_RANDOM_WORD_ATTEMPTS
+        );
+    }
+
+    fn is_valid_word(&self, word: &str) -> bool {
+        self.inner.is_valid_word(word) && (self.predicate)(word)
+    }
+
+    fn words(&self) -> Vec<&str> {
+        self.inner
+            .words()
+            .into_iter()
+            .filter(|word| (self.predicate)(word))
+            .collect()
+    }
+}
+
+/// Create a [ChainedDictionary] from the given dictionaries.
+///
+/// ```
+/// use wordler::chain_dicts;
+/// use wordler::dictionary::{Dictionary, EnglishDictionary};
+///
+/// let dictionary = chain_dicts!(
+///     EnglishDictionary::from_slice(&["CRANE"]),
+///     EnglishDictionary::from_slice(&["SLATE"]),
+/// );
+/// assert!(dictionary.is_valid_word("SLATE"));
+/// ```
+#[macro_export]
+macro_rules! chain_dicts {
+    ($($dict:expr),+ $(,)?) => {
+        $crate::dictionary::ChainedDictionary::new(vec![
+            $(Box::new($dict) as Box<dyn $crate::dictionary::Dictionary>),+
+        ])
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_repeated_letters() {
+        assert!(has_repeated_letters("GREED"));
+        assert!(has_repeated_letters("Alpha"));
+        assert!(!has_repeated_letters("GLIDE"));
+
+        let dictionary = EnglishDictionary::from_slice(&["GREED", "GLIDE", "TRULY", "KELLY"]);
+        assert_eq!(
+            dictionary.words_without_repeated_letters(),
+            vec!["GLIDE", "TRULY"]
+        );
+    }
+
+    #[test]
+    fn test_word_difficulty() {
+        let dictionary = EnglishDictionary::from_slice(&["CRANE", "CRATE", "JUMPY"]);
+        let easy = word_difficulty("CRANE", &dictionary);
+        let hard = word_difficulty("JUMPY", &dictionary);
+        assert!((0.0..=1.0).contains(&easy));
+        assert!((0.0..=1.0).contains(&hard));
+        assert!(easy < hard);
+
+        assert_eq!(dictionary.hardest_words(1), vec!["JUMPY"]);
+        assert_eq!(dictionary.easiest_words(5).len(), 3);
+        assert_eq!(dictionary.easiest_words(3)[2], "JUMPY");
+    }
+
+    #[test]
+    fn test_excluded() {
+        let dictionary =
+            EnglishDictionary::from_slice(&["GREED", "GLIDE", "TRULY"]).with_excluded(&["Greed"]);
+        assert!(!dictionary.is_valid_word("GREED"));
+        assert!(dictionary.is_valid_word("GLIDE"));
+
+        let path = std::env::temp_dir().join(format!("wordler-excluded-{}", std::process::id()));
+        std::fs::write(&path, "glide\n\n  truly \n").unwrap();
+        let dictionary = dictionary.exclude_file(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert!(!dictionary.is_valid_word("GLIDE"));
+        assert!(!dictionary.is_valid_word("TRULY"));
+        assert!(dictionary.words().is_empty());
+
+        assert!(EnglishDictionary::from_slice(&["GREED"])
+            .exclude_file("/nonexistent/wordler/excluded")
+            .is_err());
+    }
+
+    #[test]
+    fn test_chained_dictionary() {
+        let dictionary = chain_dicts!(
+            EnglishDictionary::from_slice(&["GREED"]),
+            EnglishDictionary::from_slice(&["GLIDE", "GREED"])
+        );
+        assert_eq!(dictionary.random_word(), "GREED");
+        assert!(dictionary.is_valid_word("GLIDE"));
+        assert!(!dictionary.is_valid_word("TRULY"));
+        assert_eq!(dictionary.words(), vec!["GREED", "GLIDE"]);
+        assert_eq!(dictionary.word_count(), 3);
+    }
+
+    #[test]
+    fn test_filtered_dictionary() {
+        let dictionary = FilteredDictionary::new(
+            EnglishDictionary::from_slice(&["GREED", "GLIDE", "TRULY"]),
+            |w| w.starts_with('G'),
+        );
+        assert!(dictionary.random_word().starts_with('G'));
+        assert!(dictionary.is_valid_word("GLIDE"));
+        assert!(!dictionary.is_valid_word("TRULY"));
+        assert_eq!(dictionary.word_count(), 2);
+    }
+
+    #[test]
+    fn test_filtered_dictionary_sparse() {
+        let words: Vec<String> = common_words().collect();
+        let words: Vec<&str> = words.iter().map(String::as_str).collect();
+        let dictionary =
+            FilteredDictionary::new(EnglishDictionary::from_slice(&words), |w| w == "ABOUT");
+        for _ in 0..200 {
+            assert_eq!(dictionary.random_word(), "ABOUT");
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "No word matching the predicate")]
+    fn test_filtered_dictionary_no_match() {
+        let dictionary =
+            FilteredDictionary::new(EnglishDictionary::from_slice(&["TRULY"]), |_| false);
+        dictionary.random_word();
+    }
+
+    #[test]
+    fn test_dict_path_env() {
+        let path = std::env::temp_dir().join(format!("wordler-words-{}", std::process::id()));
+        std::fs::write(&path, "crane\nCrane\nslate\ndon't\nabc\n").unwrap();
+
+        std::env::set_var(DICTIONARY_PATH_ENV, &path);
+        let from_env = EnglishDictionary::from_env();
+        let new = EnglishDictionary::new();
+        let new_from_env = EnglishDictionary::new_from_env();
+        std::env::remove_var(DICTIONARY_PATH_ENV);
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(from_env.unwrap().words(), vec!["CRANE", "SLATE"]);
+        assert_eq!(new.unwrap().words(), vec!["CRANE", "SLATE"]);
+        assert_eq!(new_from_env.unwrap().words(), vec!["CRANE", "SLATE"]);
+        assert!(matches!(
+            EnglishDictionary::from_env(),
+            Err(DictionaryError::PathNotSet("WORDLE_DICT_PATH"))
+        ));
+    }
+
+    #[test]
+    fn test_resolve_order() {
+        let dir = std::env::temp_dir().join(format!("wordler-resolve-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let user_path = dir.join("user.txt");
+        let system_path = dir.join("system.txt");
+        std::fs::write(&user_path, "crane\n").unwrap();
+        std::fs::write(&system_path, "slate\n").unwrap();
+        let system_paths = [system_path.to_str().unwrap()];
+
+        let user = EnglishDictionary::resolve(None, Some(user_path.clone()), &system_paths);
+        let system = EnglishDictionary::resolve(None, Some(dir.join("missing")), &system_paths);
+        let env = EnglishDictionary::resolve(Some(dir.join("missing")), Some(user_path), &[]);
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(user.unwrap().words(), vec!["CRANE"]);
+        assert_eq!(system.unwrap().words(), vec!["SLATE"]);
+        assert!(matches!(env, Err(DictionaryError::Io(_))));
+    }
+
+    #[cfg(not(feature = "embedded"))]
+    #[test]
+    fn test_resolve_not_found() {
+        let result = EnglishDictionary::resolve(None, None, &["/nonexistent/words"]);
+        assert!(matches!(
+            result,
+            Err(DictionaryError::FileNotFound(paths)) if paths == vec![PathBuf::from("/nonexistent/words")]
+        ));
+    }
+
+    #[cfg(feature = "embedded")]
+    #[test]
+    fn test_resolve_embedded() {
+        let dictionary = EnglishDictionary::resolve(None, None, &["/nonexistent/words"]).unwrap();
+        assert_eq!(dictionary.words(), common_words().collect::<Vec<_>>());
+        assert!(dictionary.source_path().is_none());
+    }
+
+    #[test]
+    fn test_into_iterator() {
+        let dictionary = EnglishDictionary::from_slice(&["GREED", "GLIDE"]);
+        let mut words = Vec::new();
+        for word in &dictionary {
+            words.push(word);
+        }
+        assert_eq!(words, dictionary.words());
+        assert_eq!(dictionary.iter().len(), 2);
+    }
+
+    #[test]
+    fn test_words_using_only() {
+        let dictionary = EnglishDictionary::from_slice(&["GREED", "GLIDE", "EDGER"]);
+        assert_eq!(
+            dictionary.words_using_only("gred".chars()),
+            vec!["GREED", "EDGER"]
+        );
+        assert!(dictionary.words_using_only(['G']).is_empty());
+    }
+
+    #[test]
+    fn test_word_at_index() {
+        let dictionary = EnglishDictionary::from_slice(&["GREED", "GLIDE", "EDGER"]);
+        for n in 0..dictionary.word_count() {
+            let word = dictionary.word_at_index(n).unwrap();
+            assert_eq!(dictionary.index_of(word), Some(n));
+        }
+        for word in dictionary.words() {
+            assert_eq!(
+                dictionary.word_at_index(dictionary.index_of(word).unwrap()),
+                Some(word)
+            );
+        }
+        assert_eq!(dictionary.index_of("glide"), Some(1));
+        assert_eq!(dictionary.index_of("TRULY"), None);
+        assert_eq!(dictionary.word_at_index(3), None);
+    }
+
+    #[test]
+    fn test_matching_pattern() {
+        let dictionary = EnglishDictionary::from_slice(&["GREED", "GLIDE", "EDGER"]);
+        assert_eq!(dictionary.matching_pattern("G???E").unwrap(), vec!["GLIDE"]);
+        assert_eq!(
+            dictionary.matching_pattern("g????").unwrap(),
+            vec!["GREED", "GLIDE"]
+        );
+        assert_eq!(dictionary.matching_pattern("?????").unwrap().len(), 3);
+        assert!(dictionary.matching_pattern("ZZZZZ").unwrap().is_empty());
+        assert!(matches!(
+            dictionary.matching_pattern("G??E"),
+            Err(DictionaryError::InvalidPattern(pattern)) if pattern == "G??E"
+        ));
+        assert!(matches!(
+            dictionary.matching_pattern("G?*?E"),
+            Err(DictionaryError::InvalidPattern(_))
+        ));
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn test_matching_regex() {
+        let dictionary = EnglishDictionary::from_slice(&["GREED", "GLIDE", "EDGER"]);
+        let re = regex::Regex::new("^G.*D").unwrap();
+        assert_eq!(dictionary.matching_regex(&re), vec!["GREED", "GLIDE"]);
+        let re = regex::Regex::new("EE").unwrap();
+        assert_eq!(dictionary.matching_regex(&re), vec!["GREED"]);
+    }
+
+    #[test]
+    fn test_read_errors() {
+        let path = std::env::temp_dir().join(format!("wordler-latin1-{}", std::process::id()));
+        std::fs::write(&path, b"cr\xe8me\n").unwrap();
+        let latin1 = EnglishDictionary::read(&path);
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(matches!(latin1, Err(DictionaryError::Encoding(_))));
+
+        std::fs::write(&path, "a be sea\ncrème\n").unwrap();
+        let no_words = EnglishDictionary::read(&path);
+        std::fs::remove_file(&path).unwrap();
+        assert!(matches!(
+            no_words,
+            Err(DictionaryError::NoWordsOfLength {
+                length: 5,
+                total_words: 4
+            })
+        ));
+        assert!(matches!(
+            EnglishDictionary::read(&path),
+            Err(DictionaryError::Io(e)) if e.kind() == std::io::ErrorKind::NotFound
+        ));
+    }
+
+    #[test]
+    fn test_most_common_words() {
+        let dictionary = EnglishDictionary::from_slice(&["XYLYL", "THINK", "ABOUT", "GREAT"]);
+        assert_eq!(dictionary.most_common_words(2), vec!["ABOUT", "GREAT"]);
+        assert_eq!(
+            dictionary.most_common_words(10),
+            vec!["ABOUT", "GREAT", "THINK"]
+        );
+        assert_eq!(
+            dictionary.with_common_only().words(),
+            vec!["THINK", "ABOUT", "GREAT"]
+        );
+    }
+
+    #[test]
+    fn test_two_tier_dictionary() {
+        let dictionary = EnglishDictionary::from_slice(&["XYLYL", "THINK", "ABOUT", "GREAT"])
+            .common_with_answers(2);
+        assert_eq!(dictionary.answers().words(), vec!["ABOUT", "GREAT"]);
+        assert_eq!(dictionary.valid_guesses().word_count(), 4);
+        for _ in 0..10 {
+            assert!(["ABOUT", "GREAT"].contains(&dictionary.random_word()));
+        }
+
+        let dictionary = EnglishDictionary::from_slice(&["XYLYL"])
+            .with_answer_pool(EnglishDictionary::from_slice(&["CRANE"]));
+        assert!(dictionary.is_valid_word("CRANE") && dictionary.is_valid_word("XYLYL"));
+        assert_eq!(dictionary.words(), vec!["XYLYL", "CRANE"]);
+        assert_eq!(dictionary.similar_words("CRATE", 1), vec!["CRANE"]);
+
+        // closest first across both tiers
+        let dictionary = EnglishDictionary::from_slice(&["BRINE"])
+            .with_answer_pool(EnglishDictionary::from_slice(&["CRATE"]));
+        assert_eq!(dictionary.similar_words("CRANE", 2), vec!["CRATE", "BRINE"]);
+    }
+
+    #[test]
+    fn test_nyt_style() {
+        let path = std::env::temp_dir().join(format!("wordler-nyt-{}", std::process::id()));
+        std::fs::write(&path, "xylyl\nthink\nabout\n").unwrap();
+        let dictionary = EnglishDictionary::nyt_style(&path).unwrap();
+        assert_eq!(dictionary.answers().words(), vec!["ABOUT", "THINK"]);
+        assert!(dictionary.is_valid_word("XYLYL"));
+
+        std::fs::write(&path, "xylyl\n").unwrap();
+        assert!(matches!(
+            EnglishDictionary::nyt_style(&path),
+            Err(DictionaryError::EmptyWordList)
+        ));
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[cfg(feature = "categories")]
+    #[test]
+    fn test_categories() {
+        let categories = available_categories();
+        assert_eq!(categories, vec!["animals", "colors", "countries", "fruits"]);
+        for category in categories {
+            assert!(super::categories()[category]
+                .iter()
+                .all(|w| w.len() == 5 && w.bytes().all(|b| b.is_ascii_lowercase())));
+        }
+
+        let dictionary = || EnglishDictionary::from_slice(&["TIGER", "CRANE", "ZEBRA"]);
+        let animals = dictionary().with_category("animals").unwrap();
+        assert_eq!(animals.words(), vec!["TIGER", "ZEBRA"]);
+        assert!(!animals.is_valid_word("CRANE"));
+        assert!(matches!(
+            dictionary().with_category("planets"),
+            Err(DictionaryError::UnknownCategory(c)) if c == "planets"
+        ));
+        assert!(matches!(
+            dictionary().with_category("fruits"),
+            Err(DictionaryError::EmptyWordList)
+        ));
+    }
+
+    #[cfg(feature = "categories")]
+    #[test]
+    fn test_category_with_one_word() {
+        // like wordler-category with the embedded common words
+        let words: Vec<String> = common_words().collect();
+        let dictionary = || EnglishDictionary::from_words(words.iter().map(String::as_str));
+        let dictionary = crate::chain_dicts!(
+            dictionary().with_category("countries").unwrap(),
+            dictionary(),
+        );
+        for _ in 0..200 {
+            assert_eq!(dictionary.random_word(), "CHINA");
+        }
+    }
+
+    #[test]
+    fn test_len() {
+        assert_eq!(EnglishDictionary::from_slice(&["CRANE"]).len(), 1);
+        assert!(EnglishDictionary::from_slice(&[]).is_empty());
+
+        let dictionary = chain_dicts!(
+            EnglishDictionary::from_slice(&["CRANE"]),
+            EnglishDictionary::from_slice(&["SLATE"]),
+        );
+        assert_eq!(dictionary.len(), 2);
+        assert!(!dictionary.is_empty());
+    }
+
+    #[test]
+    fn test_common_words_list() {
+        let words: Vec<String> = common_words().collect();
+        assert!(words
+            .iter()
+            .all(|w| w.len() == 5 && w.bytes().all(|b| b.is_ascii_uppercase())));
+        assert_eq!(words.iter().collect::<HashSet<_>>().len(), words.len());
+    }
+
+    #[test]
+    fn test_common_words_exclusions() {
+        let words: HashSet<String> = common_words().collect();
+        let offensive: Vec<String> = include_str!("../assets/offensive_words.txt")
+            .lines()
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .map(str::to_uppercase)
+            .collect();
+        assert!(offensive.len() > 50);
+        assert!(offensive.iter().all(|w| !words.contains(w)));
+        for name in ["COLIN", "SARAH", "QATAR", "TYSON", "BORIS", "YAHOO"] {
+            assert!(!words.contains(name), "{name}");
+        }
+        for word in ["GRANT", "BROWN", "RIVER", "CHINA"] {
+            assert!(words.contains(word), "{word}");
+        }
+    }
+
+    #[test]
+    fn test_try_from() {
+        let dictionary = EnglishDictionary::try_from(["CRANE", "SLATE", "AUDIO"].as_ref()).unwrap();
+        assert_eq!(dictionary.word_count(), 3);
+        let dictionary: EnglishDictionary = " crane\n\nSlate \n".try_into().unwrap();
+        assert_eq!(dictionary.words(), vec!["CRANE", "SLATE"]);
+
+        let empty: &[&str] = &[];
+        assert!(matches!(
+            EnglishDictionary::try_from(empty),
+            Err(DictionaryError::EmptyWordList)
+        ));
+        assert!(matches!(
+            EnglishDictionary::try_from("\n  \n"),
+            Err(DictionaryError::EmptyWordList)
+        ));
+    }
+
+    #[test]
+    fn test_statistics() {
+        let dictionary = EnglishDictionary::from_slice(&["GREED", "GLIDE", "EDGER"]);
+        let stats = dictionary.statistics();
+        assert_eq!(stats.word_count, 3);
+        assert_eq!(stats.most_common_ending_letter, 'D');
+        assert!(std::ptr::eq(stats, dictionary.statistics()));
+
+        // the cache is dropped when words change
+        let dictionary = dictionary.with_excluded(&["GREED"]);
+        let stats = dictionary.statistics();
+        assert_eq!(stats.word_count, 2);
+        assert_eq!(stats.most_common_ending_letter, 'E');
+
+        let empty = EnglishDictionary::from_slice(&[]).statistics().clone();
+        assert_eq!(empty.word_count, 0);
+        assert_eq!(empty.unique_letters_used, 0);
+        assert_eq!(empty.most_common_starting_letter, 'A');
+    }
+
+    #[test]
+    fn test_reload() {
+        let path = std::env::temp_dir().join(format!("wordler-reload-{}", std::process::id()));
+        std::fs::write(&path, "crane\n").unwrap();
+        let mut dictionary = EnglishDictionary::read(&path).unwrap();
+        assert_eq!(dictionary.source_path(), Some(path.as_path()));
+        assert_eq!(dictionary.statistics().word_count, 1);
+
+        std::fs::write(&path, "crane\nslate\n").unwrap();
+        dictionary.reload().unwrap();
+        assert_eq!(dictionary.words(), vec!["CRANE", "SLATE"]);
+        assert_eq!(dictionary.statistics().word_count, 2);
+
+        std::fs::remove_file(&path).unwrap();
+        assert!(matches!(dictionary.reload(), Err(DictionaryError::Io(_))));
+        assert_eq!(dictionary.words(), vec!["CRANE", "SLATE"]);
+
+        let mut dictionary = EnglishDictionary::from_slice(&["CRANE"]);
+        assert_eq!(dictionary.source_path(), None);
+        assert!(matches!(
+            dictionary.reload(),
+            Err(DictionaryError::NoReloadSource)
+        ));
+    }
+
+    #[test]
+    fn test_save_load() {
+        let path = std::env::temp_dir().join(format!("wordler-cache-{}", std::process::id()));
+        let dictionary = EnglishDictionary::from_slice(&["crane", "SLATE", "JUMPY"]);
+        dictionary.save(&path).unwrap();
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            "CRANE\nSLATE\nJUMPY\n"
+        );
+
+        let loaded = EnglishDictionary::load(&path).unwrap();
+        assert_eq!(loaded.words(), dictionary.words());
+        assert_eq!(loaded.source_path(), Some(path.as_path()));
+
+        std::fs::remove_file(&path).unwrap();
+        assert!(matches!(
+            EnglishDictionary::load(&path),
+            Err(DictionaryError::Io(_))
+        ));
+    }
+
+    #[test]
+    fn test_set_operators() {
+        let a = || EnglishDictionary::from_slice(&["CRANE", "SLATE", "JUMPY"]);
+        let b = || EnglishDictionary::from_slice(&["TRULY", "SLATE", "CRANE"]);
+
+        let intersection = a() & b();
+        assert!(intersection.len() <= a().len());
+        assert_eq!(intersection.words(), vec!["CRANE", "SLATE"]);
+
+        let union = a() | b();
+        assert!(union.len() >= a().len());
+        assert_eq!(union.words(), vec!["CRANE", "SLATE", "JUMPY", "TRULY"]);
+
+        let difference = a() - b();
+        assert_eq!(difference.words(), vec!["JUMPY"]);
+        assert_eq!(difference.statistics().word_count, 1);
+    }
+
+    #[test]
+    fn test_random_n_words() {
+        let dictionary = EnglishDictionary::from_slice(&["CRANE", "SLATE", "JUMPY", "TRULY"]);
+        let words = dictionary.random_n_words(3);
+        assert_eq!(words.len(), 3);
+        assert_eq!(words.iter().collect::<HashSet<_>>().len(), 3);
+        assert_eq!(dictionary.random_n_words(10).len(), 4);
+        assert!(dictionary.random_n_words(0).is_empty());
+
+        // default implementation
+        let dictionary = FilteredDictionary::new(dictionary, |w| w != "JUMPY");
+        let mut words = dictionary.random_n_words(5);
+        words.sort_unstable();
+        assert_eq!(words, vec!["CRANE", "SLATE", "TRULY"]);
+    }
+
+    #[test]
+    fn test_levenshtein() {
+        assert_eq!(levenshtein("CRANE", "CRANE"), 0);
+        assert_eq!(levenshtein("CRANE", "CRONE"), 1);
+        assert_eq!(levenshtein("CRANE", "CRANES"), 1);
+        assert_eq!(levenshtein("CRANE", "RANE"), 1);
+        assert_eq!(levenshtein("KITTEN", "SITTING"), 3);
+        assert_eq!(levenshtein("", "ABC"), 3);
+    }
+
+    #[test]
+    fn test_similar_words() {
+        let dictionary =
+            EnglishDictionary::from_slice(&["CRONE", "SLATE", "CRANE", "TRACE", "JUMPY"]);
+        assert_eq!(dictionary.similar_words("crane", 1), vec!["CRONE"]);
+        assert_eq!(
+            dictionary.similar_words("CRAVE", 2),
+            vec!["CRANE", "CRONE", "TRACE"]
+        );
+        assert!(dictionary.similar_words("XXXXX", 2).is_empty());
+        assert_eq!(dictionary.similar_words("XXXXX", 5).len(), 5);
+
+        // the default implementation agrees with the EnglishDictionary one
+        let chained = ChainedDictionary::new(vec![Box::new(dictionary)]);
+        assert_eq!(
+            chained.similar_words("CRAVE", 2),
+            vec!["CRANE", "CRONE", "TRACE"]
+        );
     }
 }
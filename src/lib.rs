@@ -12,20 +12,18 @@
 //!
 //! Basic usage:
 //!
-//! ```
-//! use wordler::dictionary::EnglishDictionary;
+//! ```no_run
 //! use wordler::wordle::{Wordle, PlayResult};
 //!
-//! let dictionary = EnglishDictionary::new().unwrap();
-//! let mut wordle = Wordle::new(&dictionary);
+//! let mut wordle = Wordle::default();
 //! let play_result = wordle.play("dream");
 //! match play_result {
 //!   Ok(play_result) => {
 //!     println!("{}", play_result);
 //!     match play_result {
-//!         PlayResult::YouWon(_) => std::process::exit(0),
-//!         PlayResult::YouLost(_, _) => std::process::exit(1),
-//!         PlayResult::TurnResult(_) => {}
+//!         PlayResult::YouWon { .. } => std::process::exit(0),
+//!         PlayResult::YouLost { .. } => std::process::exit(1),
+//!         PlayResult::TurnResult { .. } => {}
 //!     }
 //!   }
 //!   Err(e) => println!("{}", e),
@@ -1,9 +1,36 @@
 //! A library of structs to represent [Wordle] and its lifecycle.
 //!
-use crate::dictionary::Dictionary;
-use ansi_term::Color::{Green, Red, White, RGB};
+use crate::dictionary::{self, Dictionary, DictionaryError, EnglishDictionary};
+use crate::wordle::session::GameResult;
+use crate::wordle::solver::{Constraints, HardModeValidator, HardModeViolation};
+use ansi_term::Color::{Blue, Green, Red, White, RGB};
+use ansi_term::Style;
 use anyhow::Result;
+use crossterm::cursor::MoveTo;
+use crossterm::terminal::{Clear, ClearType};
+use rand::seq::SliceRandom;
+use rand::Rng;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, HashSet};
 use std::fmt::Display;
+use std::hash::{Hash, Hasher};
+use std::io::{BufRead, Write};
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+use unicode_normalization::char::is_combining_mark;
+use unicode_normalization::UnicodeNormalization;
+
+pub mod analysis;
+#[cfg(feature = "image")]
+mod board_image;
+pub mod eval;
+#[cfg(feature = "fuzz")]
+pub mod fuzz;
+pub mod scoring;
+pub mod session;
+pub mod solver;
+pub mod stats;
+pub mod variant;
 
 /// Represents the Wordle game and its state.
 pub struct Wordle<'w> {
@@ -12,51 +39,637 @@ pub struct Wordle<'w> {
     current_attempt: u8,
     guesses: [TurnInput; 6],
     game_ended_at_attempt: u8,
+    theme: ColorTheme,
+    reject_duplicates: bool,
+    played_words: HashSet<String>,
+    hard_mode: bool,
+    guess_times: Vec<Duration>,
+    last_play_at: Instant,
+    banned_starting_letters: HashSet<char>,
+    required_starting_letter: Option<char>,
+    no_palindromes: bool,
+    only_palindromes: bool,
+}
+
+/// Pool of words the actual answer is chosen from, see [Wordle::with_difficulty].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WordleDifficulty {
+    /// The third of the dictionary easiest to discover.
+    Easy,
+    /// The middle third of the dictionary.
+    Medium,
+    /// The third of the dictionary hardest to discover.
+    Hard,
+}
+
+/// Color scheme used to render each letter's [Match].
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum ColorTheme {
+    /// Green for exact, yellow for present and red for absent letters.
+    #[default]
+    Default,
+    /// Colorblind friendly scheme: blue for exact, orange for present
+    /// and grey for absent letters.
+    HighContrast,
+    /// No colors: bold underlined for exact, underlined for present
+    /// and normal text for absent letters.
+    Monochrome,
+}
+
+impl ColorTheme {
+    /// Terminal style used to paint a letter with the given [Match].
+    pub fn style(&self, mch: &Match) -> Style {
+        match (self, mch) {
+            (ColorTheme::Default, Match::ExactLocation) => RGB(0, 0, 0).bold().on(Green),
+            (ColorTheme::Default, Match::PresentInWord) => {
+                RGB(0, 0, 0).bold().on(RGB(255, 255, 0) /* Custom Yellow */)
+            }
+            (ColorTheme::Default, Match::AbsentInWord) => White.bold().on(Red),
+            (ColorTheme::HighContrast, Match::ExactLocation) => White.bold().on(Blue),
+            (ColorTheme::HighContrast, Match::PresentInWord) => {
+                RGB(0, 0, 0).bold().on(RGB(245, 121, 58) /* Orange */)
+            }
+            (ColorTheme::HighContrast, Match::AbsentInWord) => {
+                White.bold().on(RGB(120, 124, 126) /* Grey */)
+            }
+            (ColorTheme::Monochrome, Match::ExactLocation) => Style::new().bold().underline(),
+            (ColorTheme::Monochrome, Match::PresentInWord) => Style::new().underline(),
+            (ColorTheme::Monochrome, Match::AbsentInWord) => Style::new(),
+        }
+    }
+
+    /// Style of the letters not played yet on the board.
+    fn placeholder_style(&self) -> Style {
+        match self {
+            ColorTheme::Monochrome => Style::new().dimmed(),
+            _ => RGB(120, 124, 126).on(RGB(58, 58, 60) /* Dark Grey */),
+        }
+    }
+}
+
+impl FromStr for ColorTheme {
+    type Err = anyhow::Error;
+
+    /// Parse theme names `default`, `highcontrast` and `monochrome` (case-insensitive).
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "default" => Ok(ColorTheme::Default),
+            "highcontrast" => Ok(ColorTheme::HighContrast),
+            "monochrome" => Ok(ColorTheme::Monochrome),
+            _ => Err(anyhow::anyhow!("Unknown color theme: {}", s)),
+        }
+    }
 }
 
 /// Represent the type of match for each letter in user input.
-#[derive(Debug, PartialEq)]
+///
+/// Matches are ordered by how much they reveal about a letter,
+/// `AbsentInWord < PresentInWord < ExactLocation`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
 pub enum Match {
     /// When user input letter has exact location in actual answer.
     /// For example, if the actual answer is "DREAM" and user enters "CREAM",
     ///  all 4 letters in the end "REAM" have `ExactLocation` match.
-    ExactLocation,
+    ExactLocation = 2,
     /// When user input letter is present in the actual answer, but
     /// user provided letter at a different location from actual.
     /// For example, if the actual answer is "AGILE" and user enters "EAGLE",
     /// then "AG" is present in the word just at a different location.
-    PresentInWord,
+    PresentInWord = 1,
     /// When user input letter is not present in actual answer.
     /// For example, if the actual answer is "GREAT" and user enters
     /// "TWIST", then last 4 letters "WIST" are absent in the word.
-    AbsentInWord,
+    #[default]
+    AbsentInWord = 0,
 }
 
-impl Default for Match {
-    fn default() -> Self {
-        Match::AbsentInWord
+impl Match {
+    /// Check if the letter is in the answer, i.e. the match is not [Match::AbsentInWord].
+    pub fn is_informative(&self) -> bool {
+        *self != Match::AbsentInWord
+    }
+
+    /// How much the match reveals about the letter: 0 for [Match::AbsentInWord],
+    /// 1 for [Match::PresentInWord] and 2 for [Match::ExactLocation].
+    pub fn strength(&self) -> u8 {
+        *self as u8
+    }
+}
+
+/// The opposite match: [Match::ExactLocation] and [Match::AbsentInWord] are
+/// swapped, while [Match::PresentInWord] stays the same.
+///
+/// ```
+/// use wordler::wordle::Match;
+///
+/// assert_eq!(!Match::ExactLocation, Match::AbsentInWord);
+/// assert_eq!(!Match::PresentInWord, Match::PresentInWord);
+/// ```
+impl std::ops::Not for Match {
+    type Output = Match;
+
+    fn not(self) -> Match {
+        match self {
+            Match::ExactLocation => Match::AbsentInWord,
+            Match::PresentInWord => Match::PresentInWord,
+            Match::AbsentInWord => Match::ExactLocation,
+        }
+    }
+}
+
+impl std::fmt::LowerHex for Match {
+    /// The value of the match, 0 to 2, see [TurnInput::to_compact_u8].
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::LowerHex::fmt(&(*self as u8), f)
+    }
+}
+
+impl std::fmt::UpperHex for Match {
+    /// The value of the match, 0 to 2, see [TurnInput::to_compact_u8].
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::UpperHex::fmt(&(*self as u8), f)
     }
 }
 
 /// Represents each letter entered by user and its [Match] to actual answer.
-#[derive(Debug, Default)]
+#[derive(Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
 pub struct Input {
     chr: u8,
     mch: Match,
 }
 
+impl Input {
+    /// The letter entered by user.
+    pub fn letter(&self) -> char {
+        self.chr as char
+    }
+
+    /// The [Match] of the letter to actual answer.
+    pub fn match_type(&self) -> Match {
+        self.mch
+    }
+}
+
 /// Represents all 5 letters of user input and thier [Match] outcome for actual answer.
-pub type TurnInput = [Input; 5];
+///
+/// A default TurnInput represents a row not played yet: all letters are
+/// zero bytes with [Match::AbsentInWord], see [TurnInput::is_empty].
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct TurnInput([Input; 5]);
+
+impl TurnInput {
+    /// Check if this row has not been played, i.e. no letter has been entered.
+    pub fn is_empty(&self) -> bool {
+        self.iter().all(|input| input.chr == 0)
+    }
+
+    /// Iterate over each letter [Input] in order.
+    pub fn iter(&self) -> std::slice::Iter<'_, Input> {
+        self.0.iter()
+    }
+
+    /// The letter [Input] at 0-indexed `pos`, if within the word.
+    pub fn get(&self, pos: usize) -> Option<&Input> {
+        self.0.get(pos)
+    }
+
+    /// 0-indexed positions of the letters with [Match] `m`.
+    pub fn positions_with_match(&self, m: Match) -> Vec<usize> {
+        self.iter()
+            .enumerate()
+            .filter(|(_, input)| input.mch == m)
+            .map(|(pos, _)| pos)
+            .collect()
+    }
+
+    /// Letters with [Match] `m`, in order of position.
+    pub fn letters_with_match(&self, m: Match) -> Vec<char> {
+        self.iter()
+            .filter(|input| input.mch == m)
+            .map(Input::letter)
+            .collect()
+    }
+
+    /// 0-indexed position and letter of each [Match::ExactLocation] letter.
+    pub fn exact_letters(&self) -> Vec<(usize, char)> {
+        self.iter()
+            .enumerate()
+            .filter(|(_, input)| input.mch == Match::ExactLocation)
+            .map(|(pos, input)| (pos, input.letter()))
+            .collect()
+    }
+
+    /// Partial reveal for hints: the letter for [Match::ExactLocation],
+    /// `*` for [Match::PresentInWord] and `_` for [Match::AbsentInWord].
+    ///
+    /// ```
+    /// use wordler::wordle::TurnInput;
+    ///
+    /// let turn_input = TurnInput::from_pattern("GREED", "G.Y.Y").unwrap();
+    /// assert_eq!(turn_input.masked_display(), "G_*_*");
+    /// ```
+    pub fn masked_display(&self) -> String {
+        self.iter()
+            .map(|input| match input.mch {
+                Match::ExactLocation => input.letter(),
+                Match::PresentInWord => '*',
+                Match::AbsentInWord => '_',
+            })
+            .collect()
+    }
+
+    /// Number of letters revealed, [Match::ExactLocation] or [Match::PresentInWord].
+    pub fn reveal_count(&self) -> usize {
+        self.iter()
+            .filter(|input| input.mch != Match::AbsentInWord)
+            .count()
+    }
+
+    /// Number of letters with [Match::ExactLocation].
+    pub fn letters_guessed_correctly(&self) -> u8 {
+        self.count_matches(Match::ExactLocation)
+    }
+
+    /// Number of letters with [Match::PresentInWord].
+    pub fn letters_guessed_present(&self) -> u8 {
+        self.count_matches(Match::PresentInWord)
+    }
+
+    /// Number of letters with [Match::AbsentInWord].
+    pub fn letters_missed(&self) -> u8 {
+        self.count_matches(Match::AbsentInWord)
+    }
+
+    /// How informative this guess was, from 0.0 when no letter is in the answer
+    /// to 1.0 for the answer: [eval::matches_to_score] over its maximum.
+    ///
+    /// ```
+    /// use wordler::wordle::TurnInput;
+    ///
+    /// let turn_input = TurnInput::from_pattern("BLAME", "YG.G.").unwrap();
+    /// assert_eq!(turn_input.information_score(), 0.5);
+    /// ```
+    pub fn information_score(&self) -> f64 {
+        let score = eval::matches_to_score(&self.0.each_ref().map(|input| input.mch));
+        score as f64 / (self.0.len() * 2) as f64
+    }
+
+    fn count_matches(&self, m: Match) -> u8 {
+        self.iter().filter(|input| input.mch == m).count() as u8
+    }
+
+    /// Compact pattern of the matches, `G` for [Match::ExactLocation],
+    /// `Y` for [Match::PresentInWord] and `.` for [Match::AbsentInWord].
+    ///
+    /// ```
+    /// use wordler::wordle::TurnInput;
+    ///
+    /// let turn_input = TurnInput::from_pattern("CRANE", "G.Y..").unwrap();
+    /// assert_eq!(turn_input.to_pattern_string(), "G.Y..");
+    /// ```
+    pub fn to_pattern_string(&self) -> String {
+        eval::matches_to_pattern(&self.0.each_ref().map(|input| input.mch))
+    }
+
+    /// Emoji squares of the matches as shared by players,
+    /// 🟩 for [Match::ExactLocation], 🟨 for [Match::PresentInWord] and ⬛ for [Match::AbsentInWord].
+    pub fn to_emoji_string(&self) -> String {
+        eval::matches_to_emoji(&self.0.each_ref().map(|input| input.mch))
+    }
+
+    /// Create a TurnInput from 5 `letters` and their `pattern`, see [TurnInput::to_pattern_string].
+    pub fn from_pattern(letters: &str, pattern: &str) -> Result<Self, ParseError> {
+        if letters.len() != 5 || !letters.bytes().all(|b| b.is_ascii_alphabetic()) {
+            return Err(ParseError::InvalidLetters(letters.to_string()));
+        }
+        if pattern.len() != 5 {
+            return Err(ParseError::InvalidPattern(pattern.to_string()));
+        }
+
+        let mut turn_input = TurnInput::default();
+        for ((input, chr), symbol) in turn_input
+            .iter_mut()
+            .zip(letters.bytes())
+            .zip(pattern.bytes())
+        {
+            input.chr = chr.to_ascii_uppercase();
+            input.mch = match symbol.to_ascii_uppercase() {
+                b'G' => Match::ExactLocation,
+                b'Y' => Match::PresentInWord,
+                b'.' => Match::AbsentInWord,
+                _ => return Err(ParseError::InvalidPattern(pattern.to_string())),
+            };
+        }
+        Ok(turn_input)
+    }
+
+    /// The matches as a single byte below 243, reading them as 5 base 3 digits,
+    /// see [eval::pattern_to_index]. Formatting a TurnInput as hex writes this byte.
+    ///
+    /// ```
+    /// use wordler::wordle::TurnInput;
+    ///
+    /// let turn_input = TurnInput::from_pattern("DREAM", ".GYY.").unwrap();
+    /// assert_eq!(turn_input.to_compact_u8(), 66);
+    /// assert_eq!(format!("{:02x}", turn_input), "42");
+    /// ```
+    pub fn to_compact_u8(&self) -> u8 {
+        eval::pattern_to_index(&self.0.each_ref().map(|input| input.mch))
+    }
+
+    /// Create a TurnInput from 5 `letters` and their matches as encoded by
+    /// [TurnInput::to_compact_u8].
+    pub fn from_compact_u8(n: u8, letters: [char; 5]) -> Result<TurnInput, ParseError> {
+        if n as usize >= eval::PATTERN_COUNT {
+            return Err(ParseError::InvalidPattern(n.to_string()));
+        }
+        let pattern = eval::matches_to_pattern(&eval::index_to_pattern(n));
+        TurnInput::from_pattern(&letters.iter().collect::<String>(), &pattern)
+    }
+
+    /// Parse a game log of one TurnInput per line, in the format of its `Display`.
+    ///
+    /// Blank lines are skipped.
+    ///
+    /// ```
+    /// use wordler::wordle::TurnInput;
+    ///
+    /// let log = "D:.,R:G,E:Y,A:Y,M:.\nA:G,R:G,I:G,E:G,L:G\n";
+    /// let turn_inputs = TurnInput::parse_log(log).unwrap();
+    /// assert_eq!(turn_inputs.len(), 2);
+    /// assert_eq!(turn_inputs[1].to_pattern_string(), "GGGGG");
+    /// ```
+    pub fn parse_log(log: &str) -> Result<Vec<TurnInput>, ParseError> {
+        log.lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(str::parse)
+            .collect()
+    }
+
+    /// Iterate mutably over each letter [Input] in order.
+    ///
+    /// Kept within the crate, as outcome of a play should not be altered by users.
+    pub(crate) fn iter_mut(&mut self) -> std::slice::IterMut<'_, Input> {
+        self.0.iter_mut()
+    }
+}
+
+impl std::fmt::LowerHex for TurnInput {
+    /// [TurnInput::to_compact_u8] in lowercase hex.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::LowerHex::fmt(&self.to_compact_u8(), f)
+    }
+}
+
+impl std::fmt::UpperHex for TurnInput {
+    /// [TurnInput::to_compact_u8] in uppercase hex.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::UpperHex::fmt(&self.to_compact_u8(), f)
+    }
+}
+
+/// Ordered by [TurnInput::information_score] ascending, so the most informative
+/// row is the greatest, see [best_turn_input]. Rows with the same score are
+/// ordered by their letters, then by their matches.
+///
+/// ```
+/// use wordler::wordle::TurnInput;
+///
+/// let crane = TurnInput::from_pattern("CRANE", "..Y..").unwrap();
+/// let trace = TurnInput::from_pattern("TRACE", "GGGY.").unwrap();
+/// assert!(trace > crane);
+/// assert_eq!([crane, trace].iter().max().unwrap().to_pattern_string(), "GGGY.");
+/// ```
+impl Ord for TurnInput {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        let score = |turn_input: &TurnInput| {
+            eval::matches_to_score(&turn_input.0.each_ref().map(|input| input.mch))
+        };
+        let letters = |turn_input: &TurnInput| turn_input.0.each_ref().map(|input| input.chr);
+        score(self)
+            .cmp(&score(other))
+            .then_with(|| letters(self).cmp(&letters(other)))
+            .then_with(|| self.to_compact_u8().cmp(&other.to_compact_u8()))
+    }
+}
+
+impl PartialOrd for TurnInput {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Display for TurnInput {
+    /// Each letter and its pattern symbol, see [TurnInput::to_pattern_string],
+    /// like `A:G,R:Y,I:.,E:G,L:G`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (pos, (input, symbol)) in self
+            .iter()
+            .zip(self.to_pattern_string().chars())
+            .enumerate()
+        {
+            if pos > 0 {
+                write!(f, ",")?;
+            }
+            write!(f, "{}:{}", input.letter(), symbol)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for TurnInput {
+    type Err = ParseError;
+
+    /// Parse the format of TurnInput's `Display`, symbols are case-insensitive.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut letters = String::new();
+        let mut pattern = String::new();
+        for entry in s.split(',') {
+            match entry.trim().split_once(':') {
+                Some((letter, symbol)) if letter.len() == 1 && symbol.len() == 1 => {
+                    letters.push_str(letter);
+                    pattern.push_str(symbol);
+                }
+                _ => return Err(ParseError::InvalidFormat(s.to_string())),
+            }
+        }
+        TurnInput::from_pattern(&letters, &pattern)
+    }
+}
+
+impl IntoIterator for TurnInput {
+    type Item = Input;
+    type IntoIter = std::array::IntoIter<Input, 5>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'t> IntoIterator for &'t TurnInput {
+    type Item = &'t Input;
+    type IntoIter = std::slice::Iter<'t, Input>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+/// Errors from playing a [Wordle] or evaluating guesses with [eval].
+#[derive(Debug)]
+pub enum WordleError {
+    /// The word does not have exactly 5 ASCII letters.
+    InvalidWord(String),
+    /// The word is not present in the [Dictionary], with similar words
+    /// to suggest instead, see [Dictionary::similar_words].
+    NotInDictionary(String, Vec<String>),
+    /// The word has already been played, see [Wordle::with_reject_duplicates].
+    AlreadyGuessed(String),
+    /// The word does not use all revealed letters, see [Wordle::with_hard_mode].
+    HardModeViolation(Vec<HardModeViolation>),
+    /// The word is a palindrome, see [Wordle::with_no_palindromes].
+    PalindromeNotAllowed(String),
+    /// The word is not a palindrome, see [Wordle::with_only_palindromes].
+    PalindromeRequired(String),
+    /// The game has already been won or lost.
+    GameEnded,
+    /// The game has not been won or lost yet.
+    GameStillInProgress,
+    /// Reading guesses or writing results failed, see [play_interactive].
+    Io(std::io::Error),
+    /// The [Dictionary] could not be loaded.
+    Dictionary(DictionaryError),
+    /// The guess is not valid UTF-8, see [Wordle::play_bytes].
+    InvalidEncoding(std::str::Utf8Error),
+}
+
+impl Display for WordleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WordleError::InvalidWord(word) => {
+                write!(f, "Please enter a valid word with 5 letters: {}", word)
+            }
+            WordleError::NotInDictionary(word, suggestions) => {
+                write!(f, "Word not in dictionary: {}", word)?;
+                if !suggestions.is_empty() {
+                    write!(f, ". Did you mean: {}?", suggestions.join(", "))?;
+                }
+                Ok(())
+            }
+            WordleError::AlreadyGuessed(word) => write!(f, "Word already played: {}", word),
+            WordleError::HardModeViolation(violations) => {
+                let violations: Vec<String> = violations.iter().map(|v| v.to_string()).collect();
+                write!(f, "{}.", violations.join(", "))
+            }
+            WordleError::PalindromeNotAllowed(word) => {
+                write!(f, "Palindromes are not allowed: {}", word)
+            }
+            WordleError::PalindromeRequired(word) => {
+                write!(f, "Only palindromes are allowed: {}", word)
+            }
+            WordleError::GameEnded => write!(f, "Game Ended"),
+            WordleError::GameStillInProgress => write!(f, "Game still in progress"),
+            WordleError::Io(e) => write!(f, "{}", e),
+            WordleError::Dictionary(e) => write!(f, "{}", e),
+            WordleError::InvalidEncoding(e) => write!(f, "Guess is not valid UTF-8: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for WordleError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            WordleError::Io(e) => Some(e),
+            WordleError::Dictionary(e) => Some(e),
+            WordleError::InvalidEncoding(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for WordleError {
+    fn from(e: std::io::Error) -> Self {
+        WordleError::Io(e)
+    }
+}
+
+impl From<DictionaryError> for WordleError {
+    fn from(e: DictionaryError) -> Self {
+        WordleError::Dictionary(e)
+    }
+}
+
+/// Errors from parsing a [TurnInput] from text.
+#[derive(Debug, PartialEq)]
+pub enum ParseError {
+    /// Letters are not exactly 5 ASCII letters.
+    InvalidLetters(String),
+    /// Pattern is not exactly 5 of `G`, `Y` or `.`.
+    InvalidPattern(String),
+    /// Text is not comma separated `letter:symbol` pairs, see [TurnInput]'s `FromStr`.
+    InvalidFormat(String),
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseError::InvalidLetters(letters) => {
+                write!(f, "Expected 5 letters, found: {}", letters)
+            }
+            ParseError::InvalidPattern(pattern) => {
+                write!(f, "Expected 5 of 'G', 'Y' or '.', found: {}", pattern)
+            }
+            ParseError::InvalidFormat(text) => {
+                write!(
+                    f,
+                    "Expected letter:symbol pairs like A:G,R:Y, found: {}",
+                    text
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
 
 /// Output of a single game play.
+///
+/// Every variant carries the `attempt` (1 to 6) the play was made on,
+/// so a result can be displayed apart from the [Wordle] that produced it.
+/// Cloning only copies the references to the [Wordle]'s data.
+#[derive(Clone)]
+#[must_use = "the play result contains match information that should be processed"]
 pub enum PlayResult<'w> {
     /// When game has not ended, we let user know the match that occured for their play.
-    TurnResult(&'w TurnInput),
+    TurnResult {
+        /// Attempt number of this play.
+        attempt: u8,
+        /// Match outcome of this play.
+        input: &'w TurnInput,
+        /// Hard mode rules broken by this play when hard mode is not enforced,
+        /// see [Wordle::is_hard_mode_compliant].
+        violations: Option<Vec<HardModeViolation>>,
+    },
     /// When user guesses actual answer.
-    YouWon(&'w TurnInput),
+    YouWon {
+        /// Attempt number of this play.
+        attempt: u8,
+        /// Match outcome of this play.
+        input: &'w TurnInput,
+    },
     /// When user exhaust all of the 6 attempts
     /// we let them know the actual answer.
-    YouLost(&'w TurnInput, &'w str),
+    YouLost {
+        /// Attempt number of this play.
+        attempt: u8,
+        /// Match outcome of this play.
+        input: &'w TurnInput,
+        /// The actual answer.
+        answer: &'w str,
+    },
 }
 
 impl<'w> Wordle<'w> {
@@ -68,138 +681,1115 @@ impl<'w> Wordle<'w> {
     /// ```bash no_run
     /// SEED=dream wordler
     /// ```
-    pub fn new(dictionary: &'w dyn Dictionary) -> Self {
-        let word: String;
-        if let Ok(seed) = std::env::var("SEED") {
-            let seed = seed.to_uppercase();
-            if !dictionary.is_valid_word(seed.as_str()) {
-                panic!("SEED ({}) is not a valid word in dictionary.", seed);
-            }
-            word = seed;
-        } else {
-            word = dictionary.random_word().to_uppercase();
+    ///
+    /// Fails with [WordleError::NotInDictionary] when `SEED` is not a valid word in dictionary.
+    pub fn new(dictionary: &'w dyn Dictionary) -> Result<Self, WordleError> {
+        match std::env::var("SEED") {
+            Ok(seed) => Self::new_with_word(dictionary, &seed),
+            Err(_) => Ok(Self::with_answer(
+                dictionary,
+                dictionary.random_word().to_uppercase(),
+            )),
         }
+    }
+
+    /// Create a new Wordle game with given [Dictionary] and `word` as actual answer.
+    ///
+    /// Fails when `word` is not present in the dictionary.
+    pub fn new_with_word(dictionary: &'w dyn Dictionary, word: &str) -> Result<Self, WordleError> {
+        let word = word.to_uppercase();
+        if !dictionary.is_valid_word(word.as_str()) {
+            return Err(WordleError::NotInDictionary(word, Vec::new()));
+        }
+        Ok(Self::with_answer(dictionary, word))
+    }
+
+    /// Create a new Wordle game with given [Dictionary], the actual answer drawn with `rng`
+    /// instead of the dictionary's own randomness, see [Dictionary::random_word_with_rng].
+    ///
+    /// Fails when the drawn word is not valid in the dictionary.
+    ///
+    /// ```
+    /// use rand::{rngs::StdRng, SeedableRng};
+    /// use wordler::dictionary::EnglishDictionary;
+    /// use wordler::wordle::Wordle;
+    ///
+    /// let dictionary = EnglishDictionary::from_slice(&["CRANE", "SLATE", "TRACE"]);
+    /// let wordle = Wordle::with_seed_rng(&dictionary, StdRng::seed_from_u64(7)).unwrap();
+    /// ```
+    pub fn with_seed_rng<R: Rng + 'static>(
+        dictionary: &'w dyn Dictionary,
+        mut rng: R,
+    ) -> Result<Self, WordleError> {
+        let word = dictionary.random_word_with_rng(&mut rng).to_string();
+        Self::new_with_word(dictionary, &word)
+    }
 
+    fn with_answer(dictionary: &'w dyn Dictionary, word: String) -> Self {
         Wordle {
             dictionary,
             word,
             current_attempt: Default::default(),
             guesses: Default::default(),
             game_ended_at_attempt: 128,
+            theme: ColorTheme::default(),
+            reject_duplicates: false,
+            played_words: HashSet::new(),
+            hard_mode: false,
+            guess_times: Vec::new(),
+            last_play_at: Instant::now(),
+            banned_starting_letters: HashSet::new(),
+            required_starting_letter: None,
+            no_palindromes: false,
+            only_palindromes: false,
+        }
+    }
+
+    /// The actual answer, without ending the game.
+    ///
+    /// Only available with the `reveal` feature, for integration tests.
+    #[cfg(feature = "reveal")]
+    pub fn peek_answer(&self) -> &str {
+        self.word.as_str()
+    }
+
+    /// Check if the actual answer contains `ch`, in any case.
+    ///
+    /// Only available in tests and with the `reveal` feature.
+    #[cfg(any(test, feature = "reveal"))]
+    pub fn word_contains(&self, ch: char) -> bool {
+        self.word.contains(ch.to_ascii_uppercase())
+    }
+
+    /// Check if the actual answer has `ch`, in any case, at the 0-indexed `pos`.
+    ///
+    /// Only available in tests and with the `reveal` feature.
+    #[cfg(any(test, feature = "reveal"))]
+    pub fn word_has_letter_at(&self, ch: char, pos: usize) -> bool {
+        self.word.chars().nth(pos) == Some(ch.to_ascii_uppercase())
+    }
+
+    /// Create a new Wordle game with the shared [EnglishDictionary::global].
+    pub fn default_game() -> Result<Wordle<'static>, WordleError> {
+        Wordle::new(EnglishDictionary::global()?)
+    }
+
+    /// Choose a new actual answer from the [WordleDifficulty] pool of the dictionary,
+    /// ranking its words by [dictionary::word_difficulty].
+    ///
+    /// Dictionaries which cannot list their words keep the current answer.
+    pub fn with_difficulty(mut self, difficulty: WordleDifficulty) -> Self {
+        let words = dictionary::rank_by_ease(self.dictionary.words());
+        let third = words.len() / 3;
+        let pool = match difficulty {
+            WordleDifficulty::Easy => &words[..third],
+            WordleDifficulty::Medium => &words[third..words.len() - third],
+            WordleDifficulty::Hard => &words[words.len() - third..],
+        };
+        let pool = if pool.is_empty() { &words[..] } else { pool };
+        let pool: Vec<&str> = pool
+            .iter()
+            .copied()
+            .filter(|word| self.is_allowed_answer(word))
+            .collect();
+        if let Some(word) = pool.choose(&mut rand::thread_rng()) {
+            self.word = word.to_uppercase();
+        }
+        self
+    }
+
+    /// Never choose an actual answer starting with any of `letters`, in any case,
+    /// choosing a new one if needed.
+    ///
+    /// The current answer is kept when no word of the dictionary qualifies.
+    pub fn with_banned_starting_letters(mut self, letters: &[char]) -> Self {
+        self.banned_starting_letters
+            .extend(letters.iter().map(char::to_ascii_uppercase));
+        self.resample_answer();
+        self
+    }
+
+    /// Only choose an actual answer starting with `letter`, in any case,
+    /// choosing a new one if needed.
+    ///
+    /// The current answer is kept when no word of the dictionary qualifies.
+    pub fn with_required_starting_letter(mut self, letter: char) -> Self {
+        self.required_starting_letter = Some(letter.to_ascii_uppercase());
+        self.resample_answer();
+        self
+    }
+
+    /// Check if `word` satisfies the starting letter options.
+    fn is_allowed_answer(&self, word: &str) -> bool {
+        let first = match word.chars().next() {
+            Some(first) => first.to_ascii_uppercase(),
+            None => return false,
+        };
+        !self.banned_starting_letters.contains(&first)
+            && self.required_starting_letter.is_none_or(|l| l == first)
+    }
+
+    /// Choose a new actual answer if the current one is not allowed.
+    ///
+    /// Dictionaries which cannot list their words are drawn from a bounded number of times.
+    fn resample_answer(&mut self) {
+        if self.is_allowed_answer(&self.word) {
+            return;
+        }
+        let allowed: Vec<&str> = self
+            .dictionary
+            .words()
+            .into_iter()
+            .filter(|word| self.is_allowed_answer(word))
+            .collect();
+        let word = match allowed.choose(&mut rand::thread_rng()) {
+            Some(word) => Some(*word),
+            None => (0..RESAMPLE_ANSWER_ATTEMPTS)
+                .map(|_| self.dictionary.random_word())
+                .find(|word| self.is_allowed_answer(word)),
+        };
+        if let Some(word) = word {
+            self.word = word.to_uppercase();
         }
     }
 
+    /// Reject words which have already been played with [WordleError::AlreadyGuessed],
+    /// instead of spending an attempt on them.
+    pub fn with_reject_duplicates(mut self, reject_duplicates: bool) -> Self {
+        self.reject_duplicates = reject_duplicates;
+        self
+    }
+
+    /// Enforce hard mode: letters revealed by previous plays must be used
+    /// in subsequent ones, otherwise play fails with [WordleError::HardModeViolation].
+    pub fn with_hard_mode(mut self, hard_mode: bool) -> Self {
+        self.hard_mode = hard_mode;
+        self
+    }
+
+    /// Reject palindromes like `LEVEL` with [WordleError::PalindromeNotAllowed],
+    /// see [eval::is_palindrome]. Enabling it disables [Wordle::with_only_palindromes].
+    pub fn with_no_palindromes(mut self, no_palindromes: bool) -> Self {
+        self.no_palindromes = no_palindromes;
+        self.only_palindromes &= !no_palindromes;
+        self
+    }
+
+    /// Reject any word but palindromes with [WordleError::PalindromeRequired],
+    /// see [eval::is_palindrome]. Enabling it disables [Wordle::with_no_palindromes].
+    pub fn with_only_palindromes(mut self, only_palindromes: bool) -> Self {
+        self.only_palindromes = only_palindromes;
+        self.no_palindromes &= !only_palindromes;
+        self
+    }
+
+    /// Check if hard mode is enforced.
+    pub fn is_hard_mode(&self) -> bool {
+        self.hard_mode
+    }
+
+    /// Use the given [ColorTheme] to render this game.
+    pub fn with_color_theme(mut self, theme: ColorTheme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    /// The [ColorTheme] used to render this game.
+    pub fn color_theme(&self) -> ColorTheme {
+        self.theme
+    }
+
+    /// Identifier of this game derived from the actual answer, see [game_id_for_word].
+    pub fn game_id(&self) -> u64 {
+        game_id_for_word(&self.word)
+    }
+
     /// The attempt number for the current play.
+    #[deprecated(since = "0.4.0", note = "use `next_attempt_number` or `attempts_made`")]
     pub fn current_attempt(&self) -> u8 {
+        self.next_attempt_number()
+    }
+
+    /// Number of guesses played so far, 0 to 6.
+    pub fn attempts_made(&self) -> u8 {
+        self.current_attempt
+    }
+
+    /// The attempt number of the next play, 1 to 6, as displayed to the user.
+    pub fn next_attempt_number(&self) -> u8 {
         self.current_attempt + 1
     }
 
+    /// [Constraints] on the actual answer from all plays so far.
+    pub fn constraints(&self) -> Constraints {
+        Constraints::from_guesses(&self.guesses[..self.current_attempt as usize])
+    }
+
+    /// Check if the game has been won or lost.
+    pub fn is_ended(&self) -> bool {
+        self.game_ended_at_attempt <= 6
+    }
+
+    /// Check if the actual answer has been guessed.
+    pub fn is_won(&self) -> bool {
+        self.is_ended()
+            && self.guesses[self.game_ended_at_attempt as usize - 1]
+                .iter()
+                .all(|input| input.mch == Match::ExactLocation)
+    }
+
+    /// Text summary of an ended game to share, like:
+    ///
+    /// ```text
+    /// Wordle 451 3/6*
+    ///
+    /// ⬛🟨⬛⬛⬛
+    /// ⬛🟩🟩⬛🟨
+    /// 🟩🟩🟩🟩🟩
+    ///
+    /// ⏱ 1:23
+    /// ```
+    ///
+    /// with `X` instead of the attempts when lost and `*` when played in `hard_mode`,
+    /// and the [Wordle::total_time] taken in minutes and seconds.
+    /// Returns `None` while the game is in progress.
+    pub fn as_share_text(&self, game_number: Option<u32>, hard_mode: bool) -> Option<String> {
+        if !self.is_ended() {
+            return None;
+        }
+
+        let mut text = String::from("Wordle");
+        if let Some(game_number) = game_number {
+            text.push_str(&format!(" {}", game_number));
+        }
+        if self.is_won() {
+            text.push_str(&format!(" {}/6", self.current_attempt));
+        } else {
+            text.push_str(" X/6");
+        }
+        if hard_mode {
+            text.push('*');
+        }
+        text.push('\n');
+
+        for turn_input in &self.guesses[..self.current_attempt as usize] {
+            text.push('\n');
+            text.push_str(&turn_input.to_emoji_string());
+        }
+
+        let seconds = self.total_time().as_secs();
+        text.push_str(&format!("\n\n⏱ {}:{:02}", seconds / 60, seconds % 60));
+        Some(text)
+    }
+
+    /// Time taken to play the guess on 0-indexed `attempt`, if it has been played.
+    ///
+    /// Measured from the previous successful [Wordle::play], or from the
+    /// creation of the game for the first guess.
+    pub fn guess_time(&self, attempt: u8) -> Option<Duration> {
+        self.guess_times.get(attempt as usize).copied()
+    }
+
+    /// Time taken by all guesses played so far.
+    pub fn total_time(&self) -> Duration {
+        self.guess_times.iter().sum()
+    }
+
+    /// Average time taken per guess, `None` before the first guess.
+    pub fn average_guess_time(&self) -> Option<Duration> {
+        if self.guess_times.is_empty() {
+            return None;
+        }
+        Some(self.total_time() / self.guess_times.len() as u32)
+    }
+
+    /// The guess made on 0-indexed `attempt`, if it has been played.
+    pub fn get_guess(&self, attempt: u8) -> Option<&TurnInput> {
+        self.guesses[..self.current_attempt as usize].get(attempt as usize)
+    }
+
+    /// Best known [Match] of each letter played so far.
+    #[must_use]
+    pub fn keyboard_state(&self) -> HashMap<char, Match> {
+        let mut state = HashMap::new();
+        for input in self.guesses[..self.current_attempt as usize]
+            .iter()
+            .flat_map(TurnInput::iter)
+        {
+            let entry = state.entry(input.letter()).or_insert(input.mch);
+            *entry = std::cmp::max(*entry, input.mch);
+        }
+        state
+    }
+
+    /// Like [Wordle::play] but the result owns its data instead of borrowing this game.
+    ///
+    /// ```
+    /// use wordler::dictionary::EnglishDictionary;
+    /// use wordler::wordle::{OwnedPlayResult, Wordle};
+    ///
+    /// let dictionary = EnglishDictionary::from_slice(&["CRANE", "SLATE"]);
+    /// let mut wordle = Wordle::new_with_word(&dictionary, "SLATE").unwrap();
+    /// let results = vec![wordle.play_owned("crane").unwrap(), wordle.play_owned("slate").unwrap()];
+    /// drop(wordle);
+    /// assert!(matches!(results[1], OwnedPlayResult::YouWon(_)));
+    /// ```
+    pub fn play_owned(&mut self, word: &str) -> Result<OwnedPlayResult, WordleError> {
+        let result = match self.play(word)? {
+            PlayResult::TurnResult { input, .. } => OwnedPlayResult::TurnResult(owned_row(input)),
+            PlayResult::YouWon { input, .. } => OwnedPlayResult::YouWon(owned_row(input)),
+            PlayResult::YouLost { answer, .. } => OwnedPlayResult::YouLost {
+                answer: answer.to_string(),
+                guesses: Vec::new(),
+            },
+        };
+        Ok(match result {
+            OwnedPlayResult::YouLost { answer, .. } => OwnedPlayResult::YouLost {
+                answer,
+                guesses: self.guesses[..self.current_attempt as usize]
+                    .iter()
+                    .map(owned_row)
+                    .collect(),
+            },
+            result => result,
+        })
+    }
+
+    /// Play each of `guesses` in order with [Wordle::play_owned], stopping after the game is won or lost.
+    ///
+    /// Invalid guesses are reported in place and do not stop the game.
+    pub fn play_vec(&mut self, guesses: Vec<&str>) -> Vec<Result<OwnedPlayResult, WordleError>> {
+        let mut results = Vec::new();
+        for guess in guesses {
+            let result = self.play_owned(guess);
+            let ended = matches!(
+                result,
+                Ok(OwnedPlayResult::YouWon(_) | OwnedPlayResult::YouLost { .. })
+            );
+            results.push(result);
+            if ended {
+                break;
+            }
+        }
+        results
+    }
+
+    /// Write the 6 rows of the board, the guesses played so far painted with
+    /// [Wordle::color_theme] and the remaining rows as grey placeholders.
+    pub fn display_board_to(&self, writer: &mut impl Write) -> std::io::Result<()> {
+        for (attempt, turn_input) in self.guesses.iter().enumerate() {
+            if attempt < self.current_attempt as usize {
+                let row = ThemedTurnInput {
+                    turn_input,
+                    theme: self.theme,
+                };
+                writeln!(writer, "{}", row)?;
+            } else {
+                writeln!(
+                    writer,
+                    "{}",
+                    self.theme.placeholder_style().paint(" _ ".repeat(5))
+                )?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Words of `dictionary` which can still be the actual answer
+    /// given the outcome of all plays so far, sorted alphabetically.
+    ///
+    /// Note that showing these to the player can reveal the answer,
+    /// especially late in the game when few words remain.
+    #[must_use]
+    pub fn possible_words<'d>(&self, dictionary: &'d dyn Dictionary) -> Vec<&'d str> {
+        let constraints = self.constraints();
+        let mut words: Vec<&str> = dictionary
+            .words()
+            .into_iter()
+            .filter(|word| constraints.is_satisfied_by(word))
+            .collect();
+        words.sort_unstable();
+        words
+    }
+
+    /// [Wordle::play] with the guess as UTF-8 `bytes`, like read from a socket.
+    ///
+    /// Fails with [WordleError::InvalidEncoding] if `bytes` are not valid UTF-8.
+    ///
+    /// ```
+    /// use wordler::dictionary::EnglishDictionary;
+    /// use wordler::wordle::{Wordle, WordleError};
+    ///
+    /// let dictionary = EnglishDictionary::from_slice(&["CRANE", "TRACE"]);
+    /// let mut wordle = Wordle::new_with_word(&dictionary, "TRACE").unwrap();
+    /// assert!(!wordle.play_bytes(b"CRANE").unwrap().is_terminal());
+    /// assert!(matches!(
+    ///     wordle.play_bytes(b"CR\xffNE"),
+    ///     Err(WordleError::InvalidEncoding(_))
+    /// ));
+    /// ```
+    #[must_use = "an invalid guess is only reported in the returned error"]
+    pub fn play_bytes(&mut self, bytes: &[u8]) -> Result<PlayResult<'_>, WordleError> {
+        let word = std::str::from_utf8(bytes).map_err(WordleError::InvalidEncoding)?;
+        self.play(word)
+    }
+
     /// Take user input as `word` and return the play outcome.
-    pub fn play(&mut self, word: &str) -> Result<PlayResult> {
+    ///
+    /// `word` is case-insensitive, and accents or full-width forms of letters
+    /// are accepted as the plain letter, like `crème` for `CREME`.
+    #[must_use = "an invalid guess is only reported in the returned error"]
+    pub fn play(&mut self, word: &str) -> Result<PlayResult<'_>, WordleError> {
         if self.game_ended_at_attempt <= self.current_attempt + 1 {
-            return Err(anyhow::anyhow!("Game Ended"));
+            return Err(WordleError::GameEnded);
         }
 
-        if word.len() > 5 || word.len() < 5 {
-            return Err(anyhow::anyhow!("Please enter a valid word with 5 letters."));
+        let word = validate_guess(self.dictionary, word)?;
+
+        if self.reject_duplicates && self.played_words.contains(&word) {
+            return Err(WordleError::AlreadyGuessed(word));
         }
 
-        let word = word.to_uppercase();
-        if self.dictionary.is_valid_word(word.as_str()) {
-            let current_attempt = self.current_attempt as usize;
-            self.current_attempt += 1;
-            let mut input_letter_count = [0_u8; 26];
-            for ch in self.word.as_bytes() {
-                input_letter_count[(*ch - b'A') as usize] += 1
-            }
-
-            let mut processed: Vec<i8> = vec![1, 2, 3, 4, 5];
-            let turn_input = &mut self.guesses[current_attempt];
-
-            // first process exact matches
-            for (idx, ch) in word.as_bytes().iter().enumerate() {
-                turn_input[idx].chr = *ch;
-                if self.word.as_bytes()[idx] == *ch {
-                    turn_input[idx].mch = Match::ExactLocation;
-                    input_letter_count[(ch - b'A') as usize] -= 1;
-                    processed[idx] = -processed[idx];
-                }
+        let palindrome = eval::is_palindrome(&word);
+        if self.no_palindromes && palindrome {
+            return Err(WordleError::PalindromeNotAllowed(word));
+        }
+        if self.only_palindromes && !palindrome {
+            return Err(WordleError::PalindromeRequired(word));
+        }
+
+        let violations = self.is_hard_mode_compliant(&word).err();
+        if self.hard_mode {
+            if let Some(violations) = violations {
+                return Err(WordleError::HardModeViolation(violations));
             }
+        }
 
-            // process remaining letters (not present in word, or present in word)
-            for position in processed.iter() {
-                if *position > 0_i8 {
-                    let index = (*position - 1) as usize;
-                    let input_ch = word.as_bytes().get(index).unwrap();
-                    let index_in_count = (*input_ch - b'A') as usize;
-                    if input_letter_count[index_in_count] > 0 {
-                        turn_input[index].mch = Match::PresentInWord;
-                        input_letter_count[index_in_count] -= 1;
-                    }
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            "evaluating guess {} against answer at attempt {}",
+            word,
+            self.current_attempt + 1
+        );
+        let matches = eval::evaluate_guess(word.as_str(), self.word.as_str())?;
+        Ok(self.record_turn(word, matches, violations))
+    }
+
+    /// Check `guess` against the hard mode rules given the letters revealed so far,
+    /// reporting every rule it breaks, whether hard mode is enforced or not.
+    ///
+    /// ```
+    /// use wordler::dictionary::EnglishDictionary;
+    /// use wordler::wordle::Wordle;
+    ///
+    /// let dictionary = EnglishDictionary::from_slice(&["CRANE", "TRACE", "JUMPY"]);
+    /// let mut wordle = Wordle::new_with_word(&dictionary, "TRACE").unwrap();
+    /// let _ = wordle.play("crane").unwrap();
+    /// assert!(wordle.is_hard_mode_compliant("trace").is_ok());
+    /// assert_eq!(wordle.is_hard_mode_compliant("jumpy").unwrap_err().len(), 4);
+    /// ```
+    pub fn is_hard_mode_compliant(&self, guess: &str) -> Result<(), Vec<HardModeViolation>> {
+        HardModeValidator.validate(guess, &self.constraints())
+    }
+
+    /// [Wordle::play] within a `guess` tracing span with `session_id`, `word` and
+    /// `attempt` fields, recording the [Match] of each letter as debug events,
+    /// or the error as a warning.
+    ///
+    /// `session_id` is only used to correlate the events of a game.
+    #[cfg(feature = "tracing")]
+    pub fn play_logged(
+        &mut self,
+        word: &str,
+        session_id: &str,
+    ) -> Result<PlayResult<'_>, WordleError> {
+        let span = tracing::info_span!(
+            "guess",
+            session_id,
+            word,
+            attempt = self.next_attempt_number()
+        );
+        let _entered = span.enter();
+        match self.play(word) {
+            Ok(play_result) => {
+                for (position, input) in play_result.turn_input().iter().enumerate() {
+                    tracing::debug!(
+                        position,
+                        letter = %input.letter(),
+                        r#match = ?input.match_type(),
+                        "letter played"
+                    );
                 }
+                Ok(play_result)
+            }
+            Err(e) => {
+                tracing::warn!(error = %e, "guess rejected");
+                Err(e)
             }
+        }
+    }
+
+    /// Record `result` of `guess` obtained outside of this game, like from a server
+    /// holding the actual answer, so [Wordle::constraints] and [Wordle::possible_words]
+    /// account for it. The game is won when all of `result` are [Match::ExactLocation].
+    ///
+    /// `guess` must be 5 letters but needs not be in the dictionary,
+    /// and duplicate or hard mode checks are skipped.
+    ///
+    /// ```
+    /// use wordler::dictionary::EnglishDictionary;
+    /// use wordler::wordle::{Match, Wordle};
+    ///
+    /// let dictionary = EnglishDictionary::from_slice(&["CRANE", "SLATE", "JUMPY"]);
+    /// let mut wordle = Wordle::new_with_word(&dictionary, "CRANE").unwrap();
+    /// let absent = [Match::AbsentInWord; 5];
+    /// wordle.apply_external_result("jumpy", &absent).unwrap();
+    /// assert_eq!(wordle.possible_words(&dictionary), vec!["CRANE", "SLATE"]);
+    /// ```
+    pub fn apply_external_result(
+        &mut self,
+        guess: &str,
+        result: &[Match; 5],
+    ) -> Result<(), WordleError> {
+        self.apply_result(guess, result).map(|_| ())
+    }
+
+    /// Same as [Wordle::apply_external_result], returning the stored [TurnInput]
+    /// of `guess` with `matches`.
+    ///
+    /// ```
+    /// use wordler::dictionary::EnglishDictionary;
+    /// use wordler::wordle::{Match, Wordle};
+    ///
+    /// let dictionary = EnglishDictionary::from_slice(&["CRANE"]);
+    /// let mut wordle = Wordle::new_with_word(&dictionary, "CRANE").unwrap();
+    /// let turn_input = wordle.apply_result("trace", &[Match::AbsentInWord; 5]).unwrap();
+    /// assert_eq!(turn_input.to_string(), "T:.,R:.,A:.,C:.,E:.");
+    /// ```
+    pub fn apply_result(
+        &mut self,
+        guess: &str,
+        matches: &[Match; 5],
+    ) -> Result<&TurnInput, WordleError> {
+        if self.game_ended_at_attempt <= self.current_attempt + 1 {
+            return Err(WordleError::GameEnded);
+        }
+        let word =
+            normalize_guess(guess).ok_or_else(|| WordleError::InvalidWord(guess.to_string()))?;
+        Ok(self.record_turn(word, *matches, None).turn_input())
+    }
+
+    /// Store `matches` of `word` as the current attempt, ending the game when won or
+    /// out of attempts.
+    fn record_turn(
+        &mut self,
+        word: String,
+        matches: [Match; 5],
+        violations: Option<Vec<HardModeViolation>>,
+    ) -> PlayResult<'_> {
+        let current_attempt = self.current_attempt as usize;
+        self.current_attempt += 1;
+        let now = Instant::now();
+        self.guess_times.push(now - self.last_play_at);
+        self.last_play_at = now;
+
+        let turn_input = &mut self.guesses[current_attempt];
+        for ((input, ch), mch) in turn_input.iter_mut().zip(word.bytes()).zip(matches) {
+            input.chr = ch;
+            input.mch = mch;
+        }
+
+        let attempt = self.current_attempt;
+        if eval::is_solved(&matches) {
+            self.game_ended_at_attempt = self.current_attempt;
+            return PlayResult::YouWon {
+                attempt,
+                input: &self.guesses[current_attempt],
+            };
+        }
+        self.played_words.insert(word);
+
+        if self.current_attempt == 6 {
+            self.game_ended_at_attempt = self.current_attempt;
+            PlayResult::YouLost {
+                attempt,
+                input: &self.guesses[current_attempt],
+                answer: self.word.as_str(),
+            }
+        } else {
+            PlayResult::TurnResult {
+                attempt,
+                input: &self.guesses[current_attempt],
+                violations,
+            }
+        }
+    }
+}
+
+impl<'w> std::ops::Index<u8> for Wordle<'w> {
+    type Output = TurnInput;
+
+    /// The guess made on 0-indexed `attempt`.
+    ///
+    /// Panics if the attempt has not been played yet, see [Wordle::get_guess].
+    fn index(&self, attempt: u8) -> &TurnInput {
+        match self.get_guess(attempt) {
+            Some(turn_input) => turn_input,
+            None => panic!(
+                "Guess {} has not been played, {} guesses made so far.",
+                attempt, self.current_attempt
+            ),
+        }
+    }
+}
+
+impl Default for Wordle<'static> {
+    /// Create a new Wordle game with the shared [EnglishDictionary::global].
+    ///
+    /// Panics if the dictionary cannot be loaded, see [Wordle::default_game].
+    fn default() -> Self {
+        match Wordle::default_game() {
+            Ok(wordle) => wordle,
+            Err(e) => panic!("{}", e),
+        }
+    }
+}
+
+/// Play `wordle` interactively: read guesses line by line from `stdin` until the
+/// game ends. Before each guess `stdout` is cleared and the full board redrawn,
+/// followed by the outcome of the last guess and the keyboard state.
+///
+/// Invalid guesses are reported to `stdout` and do not end the game,
+/// running out of input does with [WordleError::Io].
+///
+/// ```
+/// use std::io::Cursor;
+/// use wordler::dictionary::EnglishDictionary;
+/// use wordler::wordle::{play_interactive, Wordle};
+///
+/// let dictionary = EnglishDictionary::from_slice(&["CRANE", "SLATE"]);
+/// let mut wordle = Wordle::new_with_word(&dictionary, "SLATE").unwrap();
+/// let mut output = Vec::new();
+/// let result = play_interactive(&mut wordle, &mut Cursor::new("crane\nslate\n"), &mut output).unwrap();
+/// assert!(result.won);
+/// assert_eq!(result.attempts_used, 2);
+/// ```
+pub fn play_interactive(
+    wordle: &mut Wordle,
+    stdin: &mut impl BufRead,
+    stdout: &mut impl Write,
+) -> Result<GameResult, WordleError> {
+    let theme = wordle.color_theme();
+    let mut current_guess = String::new();
+    let mut message = None;
+    loop {
+        redraw_board(wordle, stdout)?;
+        if let Some(message) = message.take() {
+            writeln!(stdout, "{}", message)?;
+        }
+        print_keyboard_state_themed(&wordle.keyboard_state(), stdout, theme)?;
+
+        current_guess.clear();
+        writeln!(
+            stdout,
+            "Enter your guess [{}/6]",
+            wordle.next_attempt_number()
+        )?;
+        if stdin.read_line(&mut current_guess)? == 0 {
+            return Err(WordleError::Io(std::io::ErrorKind::UnexpectedEof.into()));
+        }
+        match wordle.play(current_guess.trim()) {
+            Ok(play_result) if play_result.is_terminal() => {
+                let won = play_result.is_won();
+                let attempts_used = play_result.attempt();
+                let message = outcome_message(&play_result);
+                let result = GameResult {
+                    won,
+                    attempts_used,
+                    time: Some(wordle.total_time()),
+                    game_id: Some(wordle.game_id()),
+                };
+                redraw_board(wordle, stdout)?;
+                write!(stdout, "{}", message.unwrap_or_default())?;
+                return Ok(result);
+            }
+            Ok(_) => {}
+            Err(e) => message = Some(e.to_string()),
+        }
+    }
+}
+
+/// Identifier of the game with answer `word`, in any case, to correlate logs
+/// of a game without revealing its answer.
+///
+/// The same word always has the same identifier with a given build of the crate,
+/// but it may change with the Rust version.
+///
+/// ```
+/// use wordler::wordle::game_id_for_word;
+///
+/// assert_eq!(game_id_for_word("crane"), game_id_for_word("CRANE"));
+/// assert_ne!(game_id_for_word("CRANE"), game_id_for_word("SLATE"));
+/// ```
+pub fn game_id_for_word(word: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    word.to_uppercase().hash(&mut hasher);
+    hasher.finish()
+}
+
+/// The most informative played row of `inputs`, see [TurnInput::information_score],
+/// skipping rows not played yet. None if no row was played.
+///
+/// ```
+/// use wordler::wordle::{best_turn_input, TurnInput};
+///
+/// let inputs = [
+///     TurnInput::from_pattern("CRANE", "..Y..").unwrap(),
+///     TurnInput::from_pattern("TRACE", "GGGY.").unwrap(),
+///     TurnInput::default(),
+/// ];
+/// assert_eq!(best_turn_input(&inputs), Some(&inputs[1]));
+/// assert_eq!(best_turn_input(&[TurnInput::default()]), None);
+/// ```
+pub fn best_turn_input(inputs: &[TurnInput]) -> Option<&TurnInput> {
+    inputs.iter().filter(|input| !input.is_empty()).max()
+}
+
+fn redraw_board(wordle: &Wordle, stdout: &mut impl Write) -> std::io::Result<()> {
+    crossterm::queue!(stdout, Clear(ClearType::All), MoveTo(0, 0))?;
+    wordle.display_board_to(stdout)?;
+    writeln!(stdout)
+}
+
+/// `word` normalized by [normalize_guess], if it is a word of `dictionary`.
+///
+/// Fails with [WordleError::NotInDictionary] suggesting similar words otherwise.
+fn validate_guess(dictionary: &dyn Dictionary, word: &str) -> Result<String, WordleError> {
+    let word = match normalize_guess(word) {
+        Some(normalized) => normalized,
+        None => return Err(WordleError::InvalidWord(word.to_string())),
+    };
+    if !dictionary.is_valid_word(word.as_str()) {
+        let suggestions = dictionary
+            .similar_words(&word, MAX_SUGGESTION_EDITS)
+            .into_iter()
+            .take(MAX_SUGGESTIONS)
+            .map(String::from)
+            .collect();
+        return Err(WordleError::NotInDictionary(word, suggestions));
+    }
+    Ok(word)
+}
+
+/// Uppercase ASCII letters of `word` after Unicode compatibility decomposition,
+/// so full-width letters and accented letters, composed or not, match their
+/// plain letter. `None` unless that gives exactly 5 letters.
+fn normalize_guess(word: &str) -> Option<String> {
+    let normalized: String = word
+        .nfkd()
+        .filter(|ch| !is_combining_mark(*ch))
+        .map(|ch| ch.to_ascii_uppercase())
+        .collect();
+    if normalized.len() == 5 && normalized.bytes().all(|ch| ch.is_ascii_alphabetic()) {
+        Some(normalized)
+    } else {
+        None
+    }
+}
+
+/// Suggestions for a word not in dictionary are at most this many edits away.
+const MAX_SUGGESTION_EDITS: usize = 2;
+/// Number of suggestions for a word not in dictionary.
+const MAX_SUGGESTIONS: usize = 3;
+/// Attempts to draw an allowed answer from dictionaries which cannot list their words.
+const RESAMPLE_ANSWER_ATTEMPTS: usize = 1_000;
+
+const KEYBOARD_ROWS: [&str; 3] = ["QWERTYUIOP", "ASDFGHJKL", "ZXCVBNM"];
+
+/// Write a QWERTY keyboard with each letter colored by its best known [Match]
+/// in `state`, and white if not yet played.
+pub fn print_keyboard_state(
+    state: &HashMap<char, Match>,
+    writer: &mut impl Write,
+) -> std::io::Result<()> {
+    print_keyboard_state_themed(state, writer, ColorTheme::Default)
+}
+
+/// Write a QWERTY keyboard colored with the given [ColorTheme], see [print_keyboard_state].
+pub fn print_keyboard_state_themed(
+    state: &HashMap<char, Match>,
+    writer: &mut impl Write,
+    theme: ColorTheme,
+) -> std::io::Result<()> {
+    for (indent, row) in KEYBOARD_ROWS.iter().enumerate() {
+        write!(writer, "{:width$}", "", width = indent * 2)?;
+        for letter in row.chars() {
+            let key = format!(" {} ", letter);
+            match state.get(&letter) {
+                Some(mch) => write!(writer, "{}", theme.style(mch).paint(key))?,
+                None => write!(writer, "{}", White.bold().paint(key))?,
+            }
+        }
+        writeln!(writer)?;
+    }
+    Ok(())
+}
+
+/// Write each letter of `turn_input` painted with the style of its [Match] in `theme`.
+pub fn fmt_turn_input_themed(
+    f: &mut std::fmt::Formatter<'_>,
+    turn_input: &TurnInput,
+    theme: ColorTheme,
+) -> std::fmt::Result {
+    for input in turn_input {
+        let letters = [b' ', input.chr, b' '];
+        let letter = std::str::from_utf8(letters.as_slice()).unwrap();
+        write!(f, "{:3}", theme.style(&input.mch).paint(letter))?;
+    }
+    Ok(())
+}
+
+fn fmt_play_result(
+    f: &mut std::fmt::Formatter<'_>,
+    play_result: &PlayResult,
+    theme: ColorTheme,
+) -> std::fmt::Result {
+    write!(f, "[{}/6] ", play_result.attempt())?;
+    fmt_turn_input_themed(f, play_result.turn_input(), theme)?;
+    match outcome_message(play_result) {
+        Some(message) => write!(f, "\n{}", message),
+        None => Ok(()),
+    }
+}
+
+fn owned_row(turn_input: &TurnInput) -> Vec<(char, Match)> {
+    turn_input
+        .iter()
+        .map(|input| (input.letter(), input.match_type()))
+        .collect()
+}
+
+/// Message ending the game, with a trailing newline, if `play_result` ends it.
+fn outcome_message(play_result: &PlayResult) -> Option<String> {
+    match play_result {
+        PlayResult::TurnResult { .. } => None,
+        PlayResult::YouLost { answer, .. } => Some(lost_message(answer)),
+        PlayResult::YouWon { .. } => Some(WON_MESSAGE.to_string()),
+    }
+}
+
+const WON_MESSAGE: &str = "Congratulations you won! 🎉\n";
+
+fn lost_message(answer: &str) -> String {
+    format!("The word is {}. Ouch! 🤕\n", answer)
+}
+
+/// Display adapter for [fmt_turn_input_themed].
+struct ThemedTurnInput<'t> {
+    turn_input: &'t TurnInput,
+    theme: ColorTheme,
+}
+
+impl<'t> Display for ThemedTurnInput<'t> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt_turn_input_themed(f, self.turn_input, self.theme)
+    }
+}
+
+/// A [PlayResult] owning its data, to be kept after the [Wordle] is gone,
+/// see [Wordle::play_owned].
+///
+/// Each row is the letters played with their [Match].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[must_use = "the play result contains match information that should be processed"]
+pub enum OwnedPlayResult {
+    /// When the user input is not the actual answer and attempts remain.
+    TurnResult(Vec<(char, Match)>),
+    /// When the user input is the actual answer.
+    YouWon(Vec<(char, Match)>),
+    /// When the user input is not the actual answer in the last attempt.
+    YouLost {
+        /// The actual answer.
+        answer: String,
+        /// Every row played, in order.
+        guesses: Vec<Vec<(char, Match)>>,
+    },
+}
+
+impl From<OwnedPlayResult> for String {
+    /// Rows as letters followed by their emoji squares, then the win or lose message.
+    fn from(result: OwnedPlayResult) -> Self {
+        let fmt_row = |row: &[(char, Match)]| {
+            let letters: String = row.iter().map(|(letter, _)| letter).collect();
+            let matches: [Match; 5] =
+                std::array::from_fn(|i| row.get(i).map_or(Match::AbsentInWord, |(_, mch)| *mch));
+            format!("{} {}\n", letters, eval::matches_to_emoji(&matches))
+        };
+        match result {
+            OwnedPlayResult::TurnResult(row) => fmt_row(&row),
+            OwnedPlayResult::YouWon(row) => fmt_row(&row) + WON_MESSAGE,
+            OwnedPlayResult::YouLost { answer, guesses } => {
+                let rows: String = guesses.iter().map(|row| fmt_row(row)).collect();
+                rows + &lost_message(&answer)
+            }
+        }
+    }
+}
+
+impl<'w> PlayResult<'w> {
+    /// Attempt number of this play.
+    pub fn attempt(&self) -> u8 {
+        match self {
+            PlayResult::TurnResult { attempt, .. }
+            | PlayResult::YouWon { attempt, .. }
+            | PlayResult::YouLost { attempt, .. } => *attempt,
+        }
+    }
+
+    /// Match outcome of this play.
+    ///
+    /// Every variant, including [PlayResult::YouLost], carries the input of its play.
+    pub fn turn_input(&self) -> &'w TurnInput {
+        match self {
+            PlayResult::TurnResult { input, .. }
+            | PlayResult::YouWon { input, .. }
+            | PlayResult::YouLost { input, .. } => input,
+        }
+    }
+
+    /// Check if the game ended with this play.
+    pub fn is_terminal(&self) -> bool {
+        !matches!(self, PlayResult::TurnResult { .. })
+    }
+
+    /// Check if user guessed the actual answer with this play.
+    pub fn is_won(&self) -> bool {
+        matches!(self, PlayResult::YouWon { .. })
+    }
+
+    /// Check if user exhausted all attempts with this play.
+    pub fn is_lost(&self) -> bool {
+        matches!(self, PlayResult::YouLost { .. })
+    }
+
+    /// Hard mode rules broken by this play while hard mode is not enforced,
+    /// `None` when it broke none or the game ended with it.
+    pub fn hard_mode_violations(&self) -> Option<&[HardModeViolation]> {
+        match self {
+            PlayResult::TurnResult { violations, .. } => violations.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// The actual answer, revealed only when user lost.
+    pub fn answer(&self) -> Option<&'w str> {
+        match self {
+            PlayResult::YouLost { answer, .. } => Some(answer),
+            _ => None,
+        }
+    }
 
-            if word == self.word {
-                self.game_ended_at_attempt = self.current_attempt;
-                return Ok(PlayResult::YouWon(&self.guesses[current_attempt]));
-            }
+    /// Identifier of the game, see [Wordle::game_id], known only once
+    /// the game has ended with this play.
+    pub fn game_id(&self) -> Option<u64> {
+        match self {
+            PlayResult::TurnResult { .. } => None,
+            PlayResult::YouWon { input, .. } => Some(game_id_for_word(
+                &input.iter().map(Input::letter).collect::<String>(),
+            )),
+            PlayResult::YouLost { answer, .. } => Some(game_id_for_word(answer)),
+        }
+    }
 
-            if self.current_attempt == 6 {
-                self.game_ended_at_attempt = self.current_attempt;
-                return Ok(PlayResult::YouLost(
-                    &self.guesses[current_attempt],
-                    self.word.as_str(),
-                ));
-            } else {
-                return Ok(PlayResult::TurnResult(&self.guesses[current_attempt]));
-            }
+    /// JSON object of this result, with `type` being `turn_result`, `won` or `lost`,
+    /// the `attempt`, each letter of `inputs` with its `match` (`exact`, `present` or `absent`),
+    /// the `game_id` when won or lost and the `answer` when lost.
+    ///
+    /// ```
+    /// use wordler::dictionary::EnglishDictionary;
+    /// use wordler::wordle::Wordle;
+    ///
+    /// let dictionary = EnglishDictionary::from_slice(&["CRANE", "SLATE"]);
+    /// let mut wordle = Wordle::new_with_word(&dictionary, "SLATE").unwrap();
+    /// let json = wordle.play("CRANE").unwrap().to_json_value();
+    /// assert_eq!(json["type"], "turn_result");
+    /// assert_eq!(json["inputs"][2]["letter"], "A");
+    /// assert_eq!(json["inputs"][2]["match"], "exact");
+    /// ```
+    #[cfg(feature = "serde_json")]
+    pub fn to_json_value(&self) -> serde_json::Value {
+        let inputs: Vec<serde_json::Value> = self
+            .turn_input()
+            .iter()
+            .map(|input| {
+                let mch = match input.mch {
+                    Match::ExactLocation => "exact",
+                    Match::PresentInWord => "present",
+                    Match::AbsentInWord => "absent",
+                };
+                serde_json::json!({ "letter": input.letter().to_string(), "match": mch })
+            })
+            .collect();
+        match self {
+            PlayResult::TurnResult { attempt, .. } => serde_json::json!({
+                "type": "turn_result",
+                "attempt": attempt,
+                "inputs": inputs,
+            }),
+            PlayResult::YouWon { attempt, .. } => serde_json::json!({
+                "type": "won",
+                "attempt": attempt,
+                "inputs": inputs,
+                "game_id": self.game_id(),
+            }),
+            PlayResult::YouLost {
+                attempt, answer, ..
+            } => serde_json::json!({
+                "type": "lost",
+                "attempt": attempt,
+                "inputs": inputs,
+                "game_id": self.game_id(),
+                "answer": answer,
+            }),
         }
+    }
 
-        Err(anyhow::anyhow!(
-            "Please enter a valid word with 5 letters. Word not in dictionary: {}",
-            word
-        ))
+    /// Display this result using the given [ColorTheme] instead of the default one.
+    pub fn themed(&self, theme: ColorTheme) -> ThemedPlayResult<'_, 'w> {
+        ThemedPlayResult {
+            play_result: self,
+            theme,
+        }
     }
 }
 
-fn fmt_turn_input(f: &mut std::fmt::Formatter<'_>, turn_input: &TurnInput) -> std::fmt::Result {
-    for input in turn_input {
-        let letters = [b' ', input.chr, b' '];
-        let letter = std::str::from_utf8(letters.as_slice()).unwrap();
-        match input.mch {
-            Match::AbsentInWord => write!(f, "{:3}", White.bold().on(Red).paint(letter))?,
-            Match::ExactLocation => write!(f, "{:3}", RGB(0, 0, 0).bold().on(Green).paint(letter))?,
-            Match::PresentInWord => {
-                write!(
-                    f,
-                    "{:3}",
-                    RGB(0, 0, 0)
-                        .bold()
-                        .on(RGB(255, 255, 0) /* Custom Yellow */)
-                        .paint(letter)
-                )?
-            }
-        }
+#[cfg(feature = "serde_json")]
+impl From<PlayResult<'_>> for serde_json::Value {
+    /// See [PlayResult::to_json_value].
+    fn from(play_result: PlayResult<'_>) -> Self {
+        play_result.to_json_value()
     }
-    Ok(())
+}
+
+/// A [PlayResult] paired with the [ColorTheme] to display it with.
+pub struct ThemedPlayResult<'r, 'w> {
+    play_result: &'r PlayResult<'w>,
+    theme: ColorTheme,
 }
 
 impl<'w> Display for PlayResult<'w> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &self {
-            PlayResult::TurnResult(turn_input) => fmt_turn_input(f, turn_input),
-            PlayResult::YouLost(turn_input, word) => {
-                fmt_turn_input(f, turn_input)?;
-                writeln!(f, "\nThe word is {}. Ouch! 🤕", word)
-            }
-            PlayResult::YouWon(turn_input) => {
-                fmt_turn_input(f, turn_input)?;
-                writeln!(f, "\nCongratulations you won! 🎉")
-            }
-        }
+        fmt_play_result(f, self, ColorTheme::Default)
+    }
+}
+
+impl<'r, 'w> Display for ThemedPlayResult<'r, 'w> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt_play_result(f, self.play_result, self.theme)
     }
 }
 
+#[cfg(test)]
 mod tests {
     use super::*;
 
@@ -210,13 +1800,16 @@ mod tests {
         }
 
         fn is_valid_word(&self, word: &str) -> bool {
-            ["ARIEL", "DREAM", "DRINK", "GLIDE", "GREAT", "TREAT"].contains(&word)
+            [
+                "ARIEL", "DREAM", "DRINK", "GLIDE", "GREAT", "LEVEL", "TREAT",
+            ]
+            .contains(&word)
         }
     }
     #[test]
     fn test_win_single_attempt() {
         let test_dict = TestDict {};
-        let mut wordle = Wordle::new(&test_dict);
+        let mut wordle = Wordle::new_with_word(&test_dict, "ARIEL").unwrap();
         let play_result = wordle.play("ArIeL");
         assert!(play_result.is_ok());
         let play_result = play_result.unwrap();
@@ -245,8 +1838,10 @@ mod tests {
         ];
 
         match play_result {
-            PlayResult::YouWon(computed) => {
-                assert_eq!(computed.len(), expected_turn_input.len());
+            PlayResult::YouWon {
+                input: computed, ..
+            } => {
+                assert_eq!(computed.iter().count(), expected_turn_input.len());
                 assert!(computed
                     .iter()
                     .zip(expected_turn_input.iter())
@@ -262,20 +1857,916 @@ mod tests {
     #[test]
     fn test_win_six_attempts() {
         let test_dict = TestDict {};
-        let mut wordle = Wordle::new(&test_dict);
+        let mut wordle = Wordle::new_with_word(&test_dict, "ARIEL").unwrap();
         for word in ["DREAM", "DRINK", "GLIDE", "GREAT", "TREAT"] {
             let play_result = wordle.play(word);
             assert!(play_result.is_ok());
-            assert!(matches!(play_result, Ok(PlayResult::TurnResult(_))));
+            assert!(matches!(play_result, Ok(PlayResult::TurnResult { .. })));
         }
 
         let play_result = wordle.play("ariel");
         assert!(play_result.is_ok());
-        assert!(matches!(play_result, Ok(PlayResult::YouWon(_))));
+        assert!(matches!(
+            play_result,
+            Ok(PlayResult::YouWon { attempt: 6, .. })
+        ));
         // game ended
         assert!(wordle.play("ariel").is_err());
     }
 
+    #[test]
+    fn test_play_result_accessors() {
+        let test_dict = TestDict {};
+        let mut wordle = Wordle::new_with_word(&test_dict, "ARIEL").unwrap();
+        for word in ["DREAM", "DRINK", "GLIDE", "GREAT", "TREAT"] {
+            let play_result = wordle.play(word).unwrap();
+            assert!(!play_result.is_terminal());
+            assert!(!play_result.is_won() && !play_result.is_lost());
+            assert_eq!(play_result.answer(), None);
+        }
+
+        let play_result = wordle.play("DREAM").unwrap();
+        assert!(play_result.is_terminal());
+        assert!(play_result.is_lost());
+        assert_eq!(play_result.attempt(), 6);
+        assert_eq!(play_result.answer(), Some("ARIEL"));
+        assert_eq!(
+            play_result.turn_input().iter().next().unwrap().letter(),
+            'D'
+        );
+    }
+
+    #[test]
+    fn test_new_with_word() {
+        let test_dict = TestDict {};
+        assert!(Wordle::new_with_word(&test_dict, "dream").is_ok());
+        assert!(matches!(
+            Wordle::new_with_word(&test_dict, "xxxxx"),
+            Err(WordleError::NotInDictionary(word, _)) if word == "XXXXX"
+        ));
+    }
+
+    #[cfg(feature = "reveal")]
+    #[test]
+    fn test_peek_answer() {
+        let test_dict = TestDict {};
+        let wordle = Wordle::new_with_word(&test_dict, "dream").unwrap();
+        assert_eq!(wordle.peek_answer(), "DREAM");
+    }
+
+    #[test]
+    fn test_play_matches_agree_with_answer() {
+        const WORDS: [&str; 6] = ["GREED", "EDGER", "ARIEL", "LLAMA", "SPEED", "ERASE"];
+        let dictionary = EnglishDictionary::from_slice(&WORDS);
+        for answer in WORDS {
+            for guess in WORDS {
+                let mut wordle = Wordle::new_with_word(&dictionary, answer).unwrap();
+                let row: Vec<(char, Match)> = wordle
+                    .play(guess)
+                    .unwrap()
+                    .turn_input()
+                    .iter()
+                    .map(|input| (input.letter(), input.match_type()))
+                    .collect();
+                for (pos, &(letter, mch)) in row.iter().enumerate() {
+                    let revealed_elsewhere = row
+                        .iter()
+                        .any(|&(other, other_mch)| other == letter && other_mch.is_informative());
+                    match mch {
+                        Match::ExactLocation => assert!(wordle.word_has_letter_at(letter, pos)),
+                        Match::PresentInWord => {
+                            assert!(wordle.word_contains(letter));
+                            assert!(!wordle.word_has_letter_at(letter, pos));
+                        }
+                        Match::AbsentInWord => {
+                            assert!(!wordle.word_has_letter_at(letter, pos));
+                            assert_eq!(wordle.word_contains(letter), revealed_elsewhere);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_with_difficulty() {
+        let dictionary = EnglishDictionary::from_slice(&["CRANE", "CRATE", "JUMPY"]);
+        let mut wordle = Wordle::new_with_word(&dictionary, "CRANE")
+            .unwrap()
+            .with_difficulty(WordleDifficulty::Hard);
+        assert!(wordle.play("JUMPY").unwrap().is_won());
+    }
+
+    #[test]
+    fn test_keyboard_state() {
+        let test_dict = TestDict {};
+        let mut wordle = Wordle::new_with_word(&test_dict, "ARIEL").unwrap();
+        assert!(wordle.keyboard_state().is_empty());
+
+        assert!(wordle.play("GREAT").is_ok());
+        assert!(wordle.play("DREAM").is_ok());
+        let state = wordle.keyboard_state();
+        assert_eq!(state.len(), 7);
+        assert_eq!(state[&'R'], Match::ExactLocation);
+        assert_eq!(state[&'E'], Match::PresentInWord);
+        assert_eq!(state[&'D'], Match::AbsentInWord);
+
+        let mut keyboard = Vec::new();
+        print_keyboard_state_themed(&state, &mut keyboard, ColorTheme::Monochrome).unwrap();
+        let keyboard = String::from_utf8(keyboard).unwrap();
+        assert_eq!(keyboard.lines().count(), 3);
+        assert!(keyboard
+            .lines()
+            .all(|line| line.contains(" Q ") || line.contains(" A ") || line.contains(" Z ")));
+    }
+
+    #[test]
+    fn test_get_guess() {
+        let test_dict = TestDict {};
+        let mut wordle = Wordle::new_with_word(&test_dict, "ARIEL").unwrap();
+        assert!(wordle.get_guess(0).is_none());
+
+        assert!(wordle.play("GREAT").is_ok());
+        assert!(wordle.play("DREAM").is_ok());
+        assert_eq!(wordle[0].to_pattern_string(), ".GYY.");
+        assert_eq!(
+            wordle.get_guess(1).unwrap().iter().next().unwrap().letter(),
+            'D'
+        );
+        assert!(wordle.get_guess(2).is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "Guess 2 has not been played")]
+    fn test_index_unplayed_guess() {
+        let test_dict = TestDict {};
+        let mut wordle = Wordle::new_with_word(&test_dict, "ARIEL").unwrap();
+        assert!(wordle.play("GREAT").is_ok());
+        let _ = &wordle[2];
+    }
+
+    #[test]
+    fn test_reject_duplicates() {
+        let test_dict = TestDict {};
+        let mut wordle = Wordle::new_with_word(&test_dict, "ARIEL")
+            .unwrap()
+            .with_reject_duplicates(true);
+        assert!(wordle.play("DREAM").is_ok());
+        assert!(matches!(
+            wordle.play("dream"),
+            Err(WordleError::AlreadyGuessed(word)) if word == "DREAM"
+        ));
+        assert_eq!(wordle.next_attempt_number(), 2);
+
+        // allowed by default
+        let mut wordle = Wordle::new_with_word(&test_dict, "ARIEL").unwrap();
+        assert!(wordle.play("DREAM").is_ok());
+        assert!(wordle.play("DREAM").is_ok());
+    }
+
+    #[test]
+    fn test_palindromes() {
+        let test_dict = TestDict {};
+        let mut wordle = Wordle::new_with_word(&test_dict, "ARIEL")
+            .unwrap()
+            .with_no_palindromes(true);
+        assert!(matches!(
+            wordle.play("level"),
+            Err(WordleError::PalindromeNotAllowed(word)) if word == "LEVEL"
+        ));
+        assert!(wordle.play("DREAM").is_ok());
+        assert_eq!(wordle.next_attempt_number(), 2);
+
+        // allowed by default
+        let mut wordle = Wordle::new_with_word(&test_dict, "ARIEL").unwrap();
+        assert!(wordle.play("LEVEL").is_ok());
+
+        let mut wordle = Wordle::new_with_word(&test_dict, "ARIEL")
+            .unwrap()
+            .with_no_palindromes(true)
+            .with_only_palindromes(true);
+        assert!(matches!(
+            wordle.play("DREAM"),
+            Err(WordleError::PalindromeRequired(word)) if word == "DREAM"
+        ));
+        assert!(wordle.play("LEVEL").is_ok());
+    }
+
+    #[test]
+    fn test_turn_input_matches() {
+        // GREED played against GLIDE
+        let turn_input = TurnInput::from_pattern("GREED", "G.Y.Y").unwrap();
+        assert_eq!(turn_input.get(1).unwrap().letter(), 'R');
+        assert!(turn_input.get(5).is_none());
+        assert_eq!(
+            turn_input.positions_with_match(Match::PresentInWord),
+            vec![2, 4]
+        );
+        assert_eq!(
+            turn_input.letters_with_match(Match::AbsentInWord),
+            vec!['R', 'E']
+        );
+        assert_eq!(turn_input.exact_letters(), vec![(0, 'G')]);
+    }
+
+    #[test]
+    fn test_guess_times() {
+        let test_dict = TestDict {};
+        let mut wordle = Wordle::new_with_word(&test_dict, "ARIEL").unwrap();
+        assert_eq!(wordle.guess_time(0), None);
+        assert_eq!(wordle.total_time(), Duration::ZERO);
+        assert_eq!(wordle.average_guess_time(), None);
+
+        assert!(!wordle.play("DREAM").unwrap().is_terminal());
+        std::thread::sleep(Duration::from_millis(10));
+        assert!(wordle.play("xxxxx").is_err());
+        assert!(wordle.play("ARIEL").unwrap().is_won());
+
+        assert_eq!(wordle.guess_time(2), None);
+        let second = wordle.guess_time(1).unwrap();
+        assert!(second >= Duration::from_millis(10));
+        assert_eq!(wordle.total_time(), wordle.guess_time(0).unwrap() + second);
+        assert_eq!(wordle.average_guess_time(), Some(wordle.total_time() / 2));
+    }
+
+    #[test]
+    fn test_masked_display() {
+        // BLAME played against FLAKE
+        let turn_input = TurnInput::from_pattern("BLAME", ".GG.G").unwrap();
+        assert_eq!(turn_input.masked_display(), "_LA_E");
+        assert_eq!(turn_input.reveal_count(), 3);
+
+        let turn_input = TurnInput::from_pattern("CRANE", "GGGGG").unwrap();
+        assert_eq!(turn_input.masked_display(), "CRANE");
+        assert_eq!(turn_input.reveal_count(), 5);
+
+        assert_eq!(TurnInput::default().masked_display(), "_____");
+        assert_eq!(TurnInput::default().reveal_count(), 0);
+    }
+
+    #[cfg(feature = "tracing")]
+    #[test]
+    #[tracing_test::traced_test]
+    fn test_play_logged() {
+        let test_dict = TestDict {};
+        let mut wordle = Wordle::new_with_word(&test_dict, "ARIEL").unwrap();
+        assert!(!wordle
+            .play_logged("dream", "game-42")
+            .unwrap()
+            .is_terminal());
+        assert!(logs_contain("session_id=\"game-42\""));
+        assert!(logs_contain("attempt=1"));
+        assert!(logs_contain("position=1 letter=R match=ExactLocation"));
+
+        assert!(wordle.play_logged("xxxxx", "game-42").is_err());
+        assert!(logs_contain("guess rejected"));
+    }
+
+    #[test]
+    fn test_starting_letters() {
+        use rand::{rngs::StdRng, SeedableRng};
+
+        let dictionary = EnglishDictionary::from_slice(&["ARIEL", "ALOFT", "DREAM", "DRINK"]);
+        for seed in 0..20 {
+            // not Wordle::new, which reads SEED set by test_new_with_seed
+            let wordle = Wordle::with_seed_rng(&dictionary, StdRng::seed_from_u64(seed))
+                .unwrap()
+                .with_banned_starting_letters(&['a']);
+            assert!(!wordle.word.starts_with('A'));
+
+            let wordle = Wordle::new_with_word(&dictionary, "DREAM")
+                .unwrap()
+                .with_required_starting_letter('a')
+                .with_hard_mode(true);
+            assert!(wordle.word.starts_with('A'));
+        }
+
+        // kept when nothing qualifies, or when already allowed
+        let wordle = Wordle::new_with_word(&dictionary, "DREAM")
+            .unwrap()
+            .with_banned_starting_letters(&['A', 'D']);
+        assert_eq!(wordle.word, "DREAM");
+        let wordle = Wordle::new_with_word(&dictionary, "DREAM")
+            .unwrap()
+            .with_banned_starting_letters(&['A']);
+        assert_eq!(wordle.word, "DREAM");
+
+        // TestDict cannot list its words and only draws ARIEL
+        let test_dict = TestDict {};
+        let wordle = Wordle::new_with_word(&test_dict, "ARIEL")
+            .unwrap()
+            .with_banned_starting_letters(&['A']);
+        assert_eq!(wordle.word, "ARIEL");
+    }
+
+    #[test]
+    fn test_apply_external_result() {
+        let test_dict = TestDict {};
+        let mut wordle = Wordle::new_with_word(&test_dict, "ARIEL").unwrap();
+        let result = eval::evaluate_guess("CRANE", "TRACE").unwrap();
+        wordle.apply_external_result("crane", &result).unwrap();
+        assert_eq!(wordle.attempts_made(), 1);
+        assert_eq!(wordle[0].to_pattern_string(), "YGG.G");
+        assert!(!wordle.is_ended());
+
+        assert!(matches!(
+            wordle.apply_external_result("CRANES", &result),
+            Err(WordleError::InvalidWord(_))
+        ));
+        wordle
+            .apply_external_result("TRACE", &[Match::ExactLocation; 5])
+            .unwrap();
+        assert!(wordle.is_ended() && wordle.is_won());
+        assert!(matches!(
+            wordle.apply_external_result("TRACE", &result),
+            Err(WordleError::GameEnded)
+        ));
+    }
+
+    #[test]
+    fn test_apply_result_until_lost() {
+        let test_dict = TestDict {};
+        let mut wordle = Wordle::new_with_word(&test_dict, "ARIEL").unwrap();
+        let absent = [Match::AbsentInWord; 5];
+        for attempt in 0..6 {
+            let turn_input = wordle.apply_result("JUMPY", &absent).unwrap();
+            assert_eq!(turn_input.letters_missed(), 5);
+            assert_eq!(wordle.attempts_made(), attempt + 1);
+        }
+        assert!(wordle.is_ended() && !wordle.is_won());
+        assert!(matches!(
+            wordle.apply_result("JUMPY", &absent),
+            Err(WordleError::GameEnded)
+        ));
+    }
+
+    #[test]
+    fn test_soft_hard_mode_violations() {
+        let test_dict = TestDict {};
+        let mut wordle = Wordle::new_with_word(&test_dict, "GREAT").unwrap();
+        assert!(wordle
+            .play("treat")
+            .unwrap()
+            .hard_mode_violations()
+            .is_none());
+        assert!(wordle
+            .play("great")
+            .unwrap()
+            .hard_mode_violations()
+            .is_none());
+
+        let mut wordle = Wordle::new_with_word(&test_dict, "GREAT").unwrap();
+        let _ = wordle.play("dream").unwrap();
+        let play_result = wordle.play("glide").unwrap();
+        assert_eq!(
+            play_result.hard_mode_violations(),
+            Some(
+                &[
+                    HardModeViolation::MustUseExactAt {
+                        letter: 'R',
+                        position: 1
+                    },
+                    HardModeViolation::MustUseExactAt {
+                        letter: 'E',
+                        position: 2
+                    },
+                    HardModeViolation::MustUseExactAt {
+                        letter: 'A',
+                        position: 3
+                    },
+                ][..]
+            )
+        );
+    }
+
+    #[test]
+    fn test_play_bytes() {
+        let test_dict = TestDict {};
+        let mut wordle = Wordle::new_with_word(&test_dict, "ARIEL").unwrap();
+        assert!(matches!(
+            wordle.play_bytes(b"CRANE"),
+            Err(WordleError::NotInDictionary(_, _))
+        ));
+        assert!(matches!(
+            wordle.play_bytes(b"DRE\xc3"),
+            Err(WordleError::InvalidEncoding(_))
+        ));
+        assert!(wordle.play_bytes(b"ariel").unwrap().is_terminal());
+    }
+
+    #[test]
+    fn test_match_strength() {
+        let matches = [
+            Match::AbsentInWord,
+            Match::PresentInWord,
+            Match::ExactLocation,
+        ];
+        assert_eq!(matches.map(|mch| mch.strength()), [0, 1, 2]);
+        assert_eq!(matches.map(|mch| mch.is_informative()), [false, true, true]);
+        assert_eq!(matches.map(|mch| !!mch), matches);
+    }
+
+    #[test]
+    fn test_turn_input_ord_is_consistent_with_eq() {
+        let crane = TurnInput::from_pattern("CRANE", "Y....").unwrap();
+        let brake = TurnInput::from_pattern("BRAKE", "....Y").unwrap();
+        let crane_late = TurnInput::from_pattern("CRANE", "....Y").unwrap();
+        assert_eq!(crane.information_score(), brake.information_score());
+        assert!(brake < crane);
+        assert!(crane_late < crane);
+        assert_eq!(
+            crane.cmp(&TurnInput::from_pattern("CRANE", "Y....").unwrap()),
+            std::cmp::Ordering::Equal
+        );
+    }
+
+    #[test]
+    fn test_compact_u8() {
+        let turn_input = TurnInput::from_pattern("ARIEL", "GY.GG").unwrap();
+        let compact = turn_input.to_compact_u8();
+        assert_eq!(compact, 2 * 81 + 27 + 2 * 3 + 2);
+        assert_eq!(
+            TurnInput::from_compact_u8(compact, ['A', 'R', 'I', 'E', 'L']),
+            Ok(turn_input)
+        );
+        assert_eq!(format!("{:x}", Match::ExactLocation), "2");
+        assert_eq!(
+            format!("{:#X}", TurnInput::from_pattern("ARIEL", "GGGGG").unwrap()),
+            "0xF2"
+        );
+
+        assert_eq!(
+            TurnInput::from_compact_u8(243, ['A', 'R', 'I', 'E', 'L']),
+            Err(ParseError::InvalidPattern("243".to_string()))
+        );
+        assert_eq!(
+            TurnInput::from_compact_u8(0, ['A', 'R', 'I', 'E', '1']),
+            Err(ParseError::InvalidLetters("ARIE1".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_letter_counts() {
+        // DREAM played against ARIEL
+        let turn_input = TurnInput::from_pattern("DREAM", ".GYY.").unwrap();
+        assert_eq!(turn_input.letters_guessed_correctly(), 1);
+        assert_eq!(turn_input.letters_guessed_present(), 2);
+        assert_eq!(turn_input.letters_missed(), 2);
+        assert_eq!(turn_input.information_score(), 0.4);
+
+        let turn_input = TurnInput::from_pattern("ARIEL", "GGGGG").unwrap();
+        assert_eq!(turn_input.letters_guessed_correctly(), 5);
+        assert_eq!(turn_input.information_score(), 1.0);
+        assert_eq!(TurnInput::default().letters_missed(), 5);
+        assert_eq!(TurnInput::default().information_score(), 0.0);
+    }
+
+    #[test]
+    fn test_turn_input_default_is_empty() {
+        let turn_input = TurnInput::default();
+        assert!(turn_input.is_empty());
+        assert!(turn_input
+            .iter()
+            .all(|input| input.mch == Match::AbsentInWord));
+        assert!(!TurnInput::from_pattern("GREED", ".....")
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_input_hash_set() {
+        let inputs: HashSet<Input> = TurnInput::from_pattern("GREED", "G.YYY")
+            .unwrap()
+            .into_iter()
+            .collect();
+        // the two E's present in word are equal
+        assert_eq!(inputs.len(), 4);
+        assert!(inputs.contains(&Input {
+            chr: b'E',
+            mch: Match::PresentInWord,
+        }));
+    }
+
+    #[test]
+    fn test_hard_mode() {
+        let test_dict = TestDict {};
+        let mut wordle = Wordle::new_with_word(&test_dict, "ARIEL")
+            .unwrap()
+            .with_hard_mode(true);
+        assert!(wordle.play("DREAM").is_ok());
+        assert!(matches!(
+            wordle.play("GLIDE"),
+            Err(WordleError::HardModeViolation(violations)) if violations == vec![
+                HardModeViolation::MustUseExactAt {
+                    letter: 'R',
+                    position: 1
+                },
+                HardModeViolation::MustInclude { letter: 'A' },
+            ]
+        ));
+        assert_eq!(wordle.next_attempt_number(), 2);
+        assert!(wordle.play("GREAT").is_ok());
+    }
+
+    #[test]
+    fn test_share_text() {
+        let test_dict = TestDict {};
+        let mut wordle = Wordle::new_with_word(&test_dict, "ARIEL").unwrap();
+        assert!(wordle.play("GREAT").is_ok());
+        assert_eq!(wordle.as_share_text(Some(451), false), None);
+        assert!(!wordle.is_ended());
+
+        assert!(wordle.play("ARIEL").is_ok());
+        assert!(wordle.is_ended() && wordle.is_won());
+        let share_text = wordle.as_share_text(Some(451), true).unwrap();
+        assert!(share_text.starts_with("Wordle 451 2/6*\n\n⬛🟩🟨🟨⬛\n🟩🟩🟩🟩🟩\n\n⏱ "));
+
+        let mut wordle = Wordle::new_with_word(&test_dict, "ARIEL").unwrap();
+        for word in ["DREAM", "DRINK", "GLIDE", "GREAT", "TREAT", "DREAM"] {
+            assert!(wordle.play(word).is_ok());
+        }
+        assert!(wordle.is_ended() && !wordle.is_won());
+        let share_text = wordle.as_share_text(None, false).unwrap();
+        assert!(share_text.starts_with("Wordle X/6\n\n"));
+        assert_eq!(share_text.lines().count(), 10);
+    }
+
+    #[test]
+    fn test_new_with_seed() {
+        let test_dict = TestDict {};
+        std::env::set_var("SEED", "dream");
+        let seeded = Wordle::new(&test_dict);
+        std::env::set_var("SEED", "xxxxx");
+        let invalid = Wordle::new(&test_dict);
+        std::env::remove_var("SEED");
+
+        assert_eq!(seeded.unwrap().word, "DREAM");
+        assert!(matches!(invalid, Err(WordleError::NotInDictionary(word, _)) if word == "XXXXX"));
+        assert_eq!(Wordle::new(&test_dict).unwrap().word, "ARIEL");
+    }
+
+    #[test]
+    fn test_play_normalizes_unicode() {
+        let test_dict = TestDict {};
+        let mut wordle = Wordle::new_with_word(&test_dict, "ARIEL").unwrap();
+        // full-width letters
+        assert!(wordle.play("ＤＲＥＡＭ").is_ok());
+        // precomposed and combining accents
+        assert!(wordle.play("drèam").is_ok());
+        assert!(wordle.play("dre\u{0300}am").is_ok());
+        assert_eq!(wordle.next_attempt_number(), 4);
+
+        assert!(matches!(
+            wordle.play("dr3am"),
+            Err(WordleError::InvalidWord(_))
+        ));
+        assert!(matches!(
+            wordle.play("dreαm"),
+            Err(WordleError::InvalidWord(_))
+        ));
+        assert!(matches!(
+            wordle.play("dreamy"),
+            Err(WordleError::InvalidWord(_))
+        ));
+        assert_eq!(wordle.next_attempt_number(), 4);
+    }
+
+    #[test]
+    fn test_error_source() {
+        use std::error::Error;
+
+        let error = WordleError::from(DictionaryError::from(std::io::Error::from(
+            std::io::ErrorKind::NotFound,
+        )));
+        let source = error.source().unwrap();
+        assert!(source.is::<DictionaryError>());
+        assert!(source.source().unwrap().is::<std::io::Error>());
+        assert!(WordleError::GameEnded.source().is_none());
+    }
+
+    #[test]
+    fn test_play_result_clone() {
+        let test_dict = TestDict {};
+        let mut wordle = Wordle::new_with_word(&test_dict, "ARIEL").unwrap();
+        let result = wordle.play("DREAM").unwrap();
+        let copy = result.clone();
+        assert_eq!(copy.attempt(), 1);
+        assert!(std::ptr::eq(copy.turn_input(), result.turn_input()));
+        assert_eq!(copy.to_string(), result.to_string());
+    }
+
+    #[test]
+    fn test_play_owned() {
+        let test_dict = TestDict {};
+        let mut wordle = Wordle::new_with_word(&test_dict, "ARIEL").unwrap();
+        let mut results = Vec::new();
+        for word in ["DREAM", "DRINK", "GLIDE", "GREAT", "TREAT", "DREAM"] {
+            results.push(wordle.play_owned(word).unwrap());
+        }
+        drop(wordle);
+
+        assert_eq!(
+            results[0],
+            OwnedPlayResult::TurnResult(vec![
+                ('D', Match::AbsentInWord),
+                ('R', Match::ExactLocation),
+                ('E', Match::PresentInWord),
+                ('A', Match::PresentInWord),
+                ('M', Match::AbsentInWord),
+            ])
+        );
+        match &results[5] {
+            OwnedPlayResult::YouLost { answer, guesses } => {
+                assert_eq!(answer, "ARIEL");
+                assert_eq!(guesses.len(), 6);
+                assert_eq!(
+                    guesses[0],
+                    owned_row(&TurnInput::from_pattern("DREAM", ".GYY.").unwrap())
+                );
+            }
+            result => panic!("expected YouLost, got {:?}", result),
+        }
+
+        let text = String::from(results[5].clone());
+        assert_eq!(text.lines().count(), 7);
+        assert!(text.starts_with("DREAM ⬛🟩🟨🟨⬛\n"));
+        assert!(text.ends_with("The word is ARIEL. Ouch! 🤕\n"));
+    }
+
+    #[test]
+    fn test_play_vec() {
+        let test_dict = TestDict {};
+        let mut wordle = Wordle::new_with_word(&test_dict, "ARIEL").unwrap();
+        let results = wordle.play_vec(vec!["DREAM", "XXXXX", "GLIDE", "ARIEL", "GREAT"]);
+
+        assert_eq!(results.len(), 4);
+        assert!(matches!(results[0], Ok(OwnedPlayResult::TurnResult(_))));
+        assert!(matches!(results[1], Err(WordleError::NotInDictionary(..))));
+        assert!(matches!(results[2], Ok(OwnedPlayResult::TurnResult(_))));
+        assert!(matches!(results[3], Ok(OwnedPlayResult::YouWon(_))));
+        assert!(wordle.is_won());
+        assert!(wordle.get_guess(2).is_some() && wordle.get_guess(3).is_none());
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[test]
+    fn test_to_json_value() {
+        let test_dict = TestDict {};
+        let mut wordle = Wordle::new_with_word(&test_dict, "ARIEL").unwrap();
+        let json = wordle.play("DREAM").unwrap().to_json_value();
+        assert_eq!(json["type"], "turn_result");
+        assert_eq!(json["attempt"], 1);
+        assert_eq!(
+            json["inputs"][1],
+            serde_json::json!({ "letter": "R", "match": "exact" })
+        );
+        assert_eq!(json["inputs"][2]["match"], "present");
+        assert_eq!(json["inputs"][4]["match"], "absent");
+
+        for _ in 0..4 {
+            assert!(!wordle.play("GLIDE").unwrap().is_terminal());
+        }
+        let json = serde_json::Value::from(wordle.play("GREAT").unwrap());
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "type": "lost",
+                "attempt": 6,
+                "inputs": [
+                    { "letter": "G", "match": "absent" },
+                    { "letter": "R", "match": "exact" },
+                    { "letter": "E", "match": "present" },
+                    { "letter": "A", "match": "present" },
+                    { "letter": "T", "match": "absent" },
+                ],
+                "game_id": game_id_for_word("ARIEL"),
+                "answer": "ARIEL",
+            })
+        );
+    }
+
+    #[test]
+    fn test_not_in_dictionary_suggestions() {
+        let dictionary = EnglishDictionary::from_slice(&["CRANE", "CRONE", "SLATE", "JUMPY"]);
+        let mut wordle = Wordle::new_with_word(&dictionary, "SLATE").unwrap();
+        let error = wordle.play("CRAKE").err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "Word not in dictionary: CRAKE. Did you mean: CRANE, CRONE?"
+        );
+        assert_eq!(
+            wordle.play("XXXXX").err().unwrap().to_string(),
+            "Word not in dictionary: XXXXX"
+        );
+    }
+
+    #[test]
+    fn test_match_ordering() {
+        assert!(Match::AbsentInWord < Match::PresentInWord);
+        assert!(Match::PresentInWord < Match::ExactLocation);
+        assert_eq!(
+            Match::ExactLocation.max(Match::AbsentInWord),
+            Match::ExactLocation
+        );
+
+        let mut matches = [
+            Match::ExactLocation,
+            Match::AbsentInWord,
+            Match::PresentInWord,
+        ];
+        matches.sort();
+        assert_eq!(
+            matches,
+            [
+                Match::AbsentInWord,
+                Match::PresentInWord,
+                Match::ExactLocation
+            ]
+        );
+    }
+
+    #[test]
+    fn test_with_seed_rng() {
+        use rand::{rngs::StdRng, SeedableRng};
+
+        let dictionary =
+            EnglishDictionary::from_slice(&["ARIEL", "DREAM", "DRINK", "GLIDE", "GREAT", "TREAT"]);
+        let answers: Vec<String> = (0..2)
+            .map(|_| {
+                Wordle::with_seed_rng(&dictionary, StdRng::seed_from_u64(42))
+                    .unwrap()
+                    .word
+            })
+            .collect();
+        assert_eq!(answers[0], answers[1]);
+
+        // dictionaries without rng support fall back to random_word
+        let test_dict = TestDict {};
+        let wordle = Wordle::with_seed_rng(&test_dict, StdRng::seed_from_u64(42)).unwrap();
+        assert_eq!(wordle.word, "ARIEL");
+    }
+
+    #[test]
+    fn test_play_interactive() {
+        let test_dict = TestDict {};
+        let mut wordle = Wordle::new_with_word(&test_dict, "ARIEL")
+            .unwrap()
+            .with_color_theme(ColorTheme::Monochrome);
+        let mut stdin = std::io::Cursor::new("dream\nxxxxx\n\nariel\n".as_bytes());
+        let mut stdout = Vec::new();
+        let result = play_interactive(&mut wordle, &mut stdin, &mut stdout).unwrap();
+        assert!(result.won);
+        assert_eq!(result.attempts_used, 2);
+        assert_eq!(result.time, Some(wordle.total_time()));
+        assert_eq!(result.game_id, Some(game_id_for_word("ARIEL")));
+
+        let stdout = String::from_utf8(stdout).unwrap();
+        assert!(stdout.contains("Enter your guess [1/6]"));
+        assert!(stdout.contains("Word not in dictionary: XXXXX"));
+        assert!(stdout.contains("Please enter a valid word with 5 letters"));
+        assert!(stdout.contains("Congratulations you won!"));
+        assert_eq!(stdout.matches("Enter your guess [2/6]").count(), 3);
+    }
+
+    #[test]
+    fn test_display_board_to() {
+        let test_dict = TestDict {};
+        let mut wordle = Wordle::new_with_word(&test_dict, "ARIEL")
+            .unwrap()
+            .with_color_theme(ColorTheme::Monochrome);
+        assert!(!wordle.play("DREAM").unwrap().is_terminal());
+        assert!(!wordle.play("GLIDE").unwrap().is_terminal());
+
+        let mut board = Vec::new();
+        wordle.display_board_to(&mut board).unwrap();
+        let board = String::from_utf8(board).unwrap();
+        let rows: Vec<&str> = board.lines().collect();
+        assert_eq!(rows.len(), 6);
+        assert!(rows[0].contains(" D ") && rows[0].contains(" M "));
+        assert!(rows[1].contains(" G ") && rows[1].contains(" E "));
+        assert!(rows[2..].iter().all(|row| row.contains(&" _ ".repeat(5))));
+    }
+
+    #[test]
+    fn test_play_interactive_eof() {
+        let test_dict = TestDict {};
+        let mut wordle = Wordle::new_with_word(&test_dict, "ARIEL").unwrap();
+        let mut stdin = std::io::Cursor::new("dream\n".as_bytes());
+        let result = play_interactive(&mut wordle, &mut stdin, &mut std::io::sink());
+        assert!(
+            matches!(result, Err(WordleError::Io(e)) if e.kind() == std::io::ErrorKind::UnexpectedEof)
+        );
+    }
+
+    #[test]
+    fn test_invalid_input_does_not_advance_attempt() {
+        let test_dict = TestDict {};
+        let mut wordle = Wordle::new_with_word(&test_dict, "ARIEL").unwrap();
+        assert_eq!(wordle.next_attempt_number(), 1);
+
+        // wrong length
+        assert!(wordle.play("XY").is_err());
+        assert_eq!(wordle.next_attempt_number(), 1);
+
+        // not in dictionary
+        assert!(wordle.play("XXXXX").is_err());
+        assert_eq!(wordle.next_attempt_number(), 1);
+
+        assert!(wordle.play("DREAM").is_ok());
+        assert_eq!(wordle.next_attempt_number(), 2);
+        assert_eq!(wordle.attempts_made(), 1);
+        #[allow(deprecated)]
+        let current_attempt = wordle.current_attempt();
+        assert_eq!(current_attempt, 2);
+    }
+
+    #[test]
+    fn test_possible_words() {
+        let test_dict = TestDict {};
+        let mut wordle = Wordle::new_with_word(&test_dict, "ARIEL").unwrap();
+        let dictionary = EnglishDictionary::from_slice(&[
+            "ARIEL", "CRAVE", "DREAM", "DRINK", "GLIDE", "GREAT", "TREAT",
+        ]);
+        assert_eq!(wordle.possible_words(&dictionary).len(), 7);
+
+        assert!(wordle.play("DREAM").is_ok());
+        assert_eq!(wordle.possible_words(&dictionary), vec!["ARIEL", "CRAVE"]);
+    }
+
+    #[test]
+    fn test_turn_input_iter() {
+        let test_dict = TestDict {};
+        let mut wordle = Wordle::new_with_word(&test_dict, "ARIEL").unwrap();
+        let play_result = wordle.play("DREAM").unwrap();
+        let PlayResult::TurnResult { input, .. } = play_result else {
+            panic!()
+        };
+
+        let mut letters = String::new();
+        for letter in input {
+            letters.push(letter.letter());
+        }
+        assert_eq!(letters, "DREAM");
+
+        let exact: Vec<usize> = input
+            .iter()
+            .enumerate()
+            .filter(|(_, letter)| letter.match_type() == Match::ExactLocation)
+            .map(|(idx, _)| idx)
+            .collect();
+        assert_eq!(exact, vec![1]);
+    }
+
+    #[test]
+    fn test_pattern_string() {
+        let test_dict = TestDict {};
+        let mut wordle = Wordle::new_with_word(&test_dict, "ARIEL").unwrap();
+        let play_result = wordle.play("DREAM").unwrap();
+        assert_eq!(play_result.turn_input().to_pattern_string(), ".GYY.");
+
+        let turn_input = TurnInput::from_pattern("dream", ".gYy.").unwrap();
+        assert_eq!(turn_input.to_pattern_string(), ".GYY.");
+        assert_eq!(turn_input.iter().next().unwrap().letter(), 'D');
+
+        assert_eq!(
+            TurnInput::from_pattern("DREAMS", ".GYY.").err(),
+            Some(ParseError::InvalidLetters("DREAMS".to_string()))
+        );
+        assert_eq!(
+            TurnInput::from_pattern("DREAM", ".GYX.").err(),
+            Some(ParseError::InvalidPattern(".GYX.".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_turn_input_from_str() {
+        let turn_input = TurnInput::from_pattern("ARIEL", "GY.GG").unwrap();
+        assert_eq!(turn_input.to_string(), "A:G,R:Y,I:.,E:G,L:G");
+        assert_eq!(
+            turn_input.to_string().parse::<TurnInput>().unwrap(),
+            turn_input
+        );
+        assert_eq!(
+            "a:g, r:y, i:., e:g, l:g".parse::<TurnInput>(),
+            Ok(turn_input)
+        );
+
+        assert_eq!(
+            "A:G,R:Y,I:.,E:G".parse::<TurnInput>(),
+            Err(ParseError::InvalidLetters("ARIE".to_string()))
+        );
+        assert_eq!(
+            "A:G,R:Y,I:X,E:G,L:G".parse::<TurnInput>(),
+            Err(ParseError::InvalidPattern("GYXGG".to_string()))
+        );
+        assert_eq!(
+            "AG,R:Y,I:.,E:G,L:G".parse::<TurnInput>(),
+            Err(ParseError::InvalidFormat("AG,R:Y,I:.,E:G,L:G".to_string()))
+        );
+
+        assert!(TurnInput::parse_log("A:G,R:Y,I:.,E:G,L:G\nnot a turn\n").is_err());
+    }
+
     #[test]
     fn test_duplicate() {
         struct DupDict;
@@ -290,7 +2781,7 @@ mod tests {
         }
 
         let dup_dict = DupDict {};
-        let mut wordle = Wordle::new(&dup_dict);
+        let mut wordle = Wordle::new_with_word(&dup_dict, "GREED").unwrap();
         let play_result = wordle.play("ELITE");
         assert!(play_result.is_ok());
         let play_result = play_result.unwrap();
@@ -319,8 +2810,10 @@ mod tests {
         ];
 
         match play_result {
-            PlayResult::TurnResult(computed) => {
-                assert_eq!(computed.len(), expected_turn_input.len());
+            PlayResult::TurnResult {
+                input: computed, ..
+            } => {
+                assert_eq!(computed.iter().count(), expected_turn_input.len());
                 assert!(computed
                     .iter()
                     .zip(expected_turn_input.iter())
@@ -344,7 +2837,7 @@ mod tests {
         }
 
         let dup_dict = DupDict {};
-        let mut wordle = Wordle::new(&dup_dict);
+        let mut wordle = Wordle::new_with_word(&dup_dict, "GLIDE").unwrap();
         let play_result = wordle.play("GREED");
         assert!(play_result.is_ok());
         let play_result = play_result.unwrap();
@@ -373,8 +2866,10 @@ mod tests {
         ];
 
         match play_result {
-            PlayResult::TurnResult(computed) => {
-                assert_eq!(computed.len(), expected_turn_input.len());
+            PlayResult::TurnResult {
+                input: computed, ..
+            } => {
+                assert_eq!(computed.iter().count(), expected_turn_input.len());
                 assert!(computed
                     .iter()
                     .zip(expected_turn_input.iter())
@@ -398,7 +2893,7 @@ mod tests {
         }
 
         let dup_dict = DupDict {};
-        let mut wordle = Wordle::new(&dup_dict);
+        let mut wordle = Wordle::new_with_word(&dup_dict, "TRULY").unwrap();
         let play_result = wordle.play("KELLY");
         assert!(play_result.is_ok());
         let play_result = play_result.unwrap();
@@ -427,8 +2922,10 @@ mod tests {
         ];
 
         match play_result {
-            PlayResult::TurnResult(computed) => {
-                assert_eq!(computed.len(), expected_turn_input.len());
+            PlayResult::TurnResult {
+                input: computed, ..
+            } => {
+                assert_eq!(computed.iter().count(), expected_turn_input.len());
                 assert!(computed
                     .iter()
                     .zip(expected_turn_input.iter())
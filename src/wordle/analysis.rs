@@ -0,0 +1,368 @@
+//! Post-game analysis of the [TurnInput] rows played in a game.
+//!
+use super::eval::evaluate_guess;
+use super::solver::{turn_input, Constraints, SolveStrategy};
+use super::{ColorTheme, Match, TurnInput, Wordle};
+use crate::dictionary::Dictionary;
+use std::collections::{BTreeMap, HashSet};
+use std::io::{self, Write};
+
+/// Count of each letter `A` to `Z` guessed at each of the 5 positions across
+/// all `guesses`, indexed as `heatmap[position][letter]`.
+///
+/// Rows not played yet, see [TurnInput::is_empty], are skipped.
+///
+/// ```
+/// use wordler::wordle::TurnInput;
+/// use wordler::wordle::analysis::letter_position_heatmap;
+///
+/// let guesses: Vec<TurnInput> = ["C:.,R:G,A:Y,N:.,E:G", "T:.,R:G,E:Y,A:Y,D:."]
+///     .iter()
+///     .map(|row| row.parse().unwrap())
+///     .collect();
+/// let heatmap = letter_position_heatmap(&guesses);
+/// assert_eq!(heatmap[1][(b'R' - b'A') as usize], 2);
+/// assert_eq!(heatmap[0][(b'C' - b'A') as usize], 1);
+/// ```
+pub fn letter_position_heatmap(guesses: &[TurnInput]) -> [[u32; 26]; 5] {
+    let mut heatmap = [[0; 26]; 5];
+    for guess in guesses.iter().filter(|guess| !guess.is_empty()) {
+        for (position, input) in guess.iter().enumerate() {
+            let letter = input.letter().to_ascii_uppercase();
+            if letter.is_ascii_uppercase() {
+                heatmap[position][(letter as u8 - b'A') as usize] += 1;
+            }
+        }
+    }
+    heatmap
+}
+
+/// Count of each [Match] outcome at each of the 5 positions across all `guesses`,
+/// indexed as `heatmap[position][outcome]` where the outcome is `0` for
+/// [Match::ExactLocation], `1` for [Match::PresentInWord] and `2` for
+/// [Match::AbsentInWord].
+///
+/// Rows not played yet, see [TurnInput::is_empty], are skipped.
+pub fn match_heatmap(guesses: &[TurnInput]) -> [[u32; 3]; 5] {
+    let mut heatmap = [[0; 3]; 5];
+    for guess in guesses.iter().filter(|guess| !guess.is_empty()) {
+        for (position, input) in guess.iter().enumerate() {
+            let outcome = match input.match_type() {
+                Match::ExactLocation => 0,
+                Match::PresentInWord => 1,
+                Match::AbsentInWord => 2,
+            };
+            heatmap[position][outcome] += 1;
+        }
+    }
+    heatmap
+}
+
+/// Write `heatmap` from [letter_position_heatmap] as an ASCII table with a row
+/// per letter guessed at least once and a column per position.
+///
+/// ```
+/// use wordler::wordle::analysis::{letter_position_heatmap, print_heatmap};
+///
+/// let guesses = ["A:G,B:.,B:.,E:.,Y:G".parse().unwrap()];
+/// let mut table = Vec::new();
+/// print_heatmap(&letter_position_heatmap(&guesses), &mut table).unwrap();
+/// assert_eq!(
+///     String::from_utf8(table).unwrap(),
+///     "  | 1 2 3 4 5\n\
+///      --+----------\n\
+///      A | 1 0 0 0 0\n\
+///      B | 0 1 1 0 0\n\
+///      E | 0 0 0 1 0\n\
+///      Y | 0 0 0 0 1\n"
+/// );
+/// ```
+pub fn print_heatmap(heatmap: &[[u32; 26]; 5], writer: &mut impl Write) -> io::Result<()> {
+    let width = heatmap
+        .iter()
+        .flatten()
+        .max()
+        .map_or(1, |max| max.to_string().len());
+
+    write!(writer, "  |")?;
+    for position in 1..=heatmap.len() {
+        write!(writer, " {position:>width$}")?;
+    }
+    writeln!(writer)?;
+    writeln!(writer, "--+{}", "-".repeat((width + 1) * heatmap.len()))?;
+
+    for letter in 0..26 {
+        if heatmap.iter().all(|counts| counts[letter] == 0) {
+            continue;
+        }
+        write!(writer, "{} |", (b'A' + letter as u8) as char)?;
+        for counts in heatmap {
+            write!(writer, " {:>width$}", counts[letter])?;
+        }
+        writeln!(writer)?;
+    }
+    Ok(())
+}
+
+/// Letters marked [Match::AbsentInWord] across all `guesses` with how many times
+/// they were, most frequent first and then in alphabetical order.
+///
+/// ```
+/// use wordler::wordle::TurnInput;
+/// use wordler::wordle::analysis::common_absent_letters;
+///
+/// let guesses: Vec<TurnInput> = ["C:.,R:G,A:Y,N:.,E:G", "T:.,R:G,E:Y,A:Y,D:."]
+///     .iter()
+///     .map(|row| row.parse().unwrap())
+///     .collect();
+/// assert_eq!(
+///     common_absent_letters(&guesses),
+///     vec![('C', 1), ('D', 1), ('N', 1), ('T', 1)]
+/// );
+/// ```
+pub fn common_absent_letters(guesses: &[TurnInput]) -> Vec<(char, u32)> {
+    count_letters(guesses, Match::AbsentInWord)
+}
+
+/// Letters marked [Match::PresentInWord] across all `guesses` with how many times
+/// they were, most frequent first and then in alphabetical order.
+pub fn common_present_letters(guesses: &[TurnInput]) -> Vec<(char, u32)> {
+    count_letters(guesses, Match::PresentInWord)
+}
+
+fn count_letters(guesses: &[TurnInput], mch: Match) -> Vec<(char, u32)> {
+    let mut counts: BTreeMap<char, u32> = BTreeMap::new();
+    for input in guesses
+        .iter()
+        .filter(|guess| !guess.is_empty())
+        .flat_map(TurnInput::iter)
+        .filter(|input| input.match_type() == mch)
+    {
+        *counts
+            .entry(input.letter().to_ascii_uppercase())
+            .or_default() += 1;
+    }
+    let mut counts: Vec<(char, u32)> = counts.into_iter().collect();
+    counts.sort_by(|(_, a), (_, b)| b.cmp(a));
+    counts
+}
+
+/// The word of `dictionary` with the most distinct letters not tried in any
+/// of `guesses`, the first one in dictionary order on ties.
+///
+/// None if no word of `dictionary` has a letter not tried yet.
+///
+/// ```
+/// use wordler::dictionary::EnglishDictionary;
+/// use wordler::wordle::TurnInput;
+/// use wordler::wordle::analysis::optimal_remaining_guess;
+///
+/// let dictionary = EnglishDictionary::from_slice(&["TRACE", "SCOUT", "PUDGY"]);
+/// let guesses = ["C:.,R:G,A:Y,N:.,E:G".parse().unwrap()];
+/// assert_eq!(optimal_remaining_guess(&guesses, &dictionary).unwrap(), "PUDGY");
+/// ```
+pub fn optimal_remaining_guess(
+    guesses: &[TurnInput],
+    dictionary: &dyn Dictionary,
+) -> Option<String> {
+    let tried: HashSet<char> = guesses
+        .iter()
+        .flat_map(TurnInput::iter)
+        .map(|input| input.letter().to_ascii_uppercase())
+        .collect();
+    let coverage = |word: &str| {
+        word.to_ascii_uppercase()
+            .chars()
+            .filter(|letter| !tried.contains(letter))
+            .collect::<HashSet<char>>()
+            .len()
+    };
+    let mut best: Option<(&str, usize)> = None;
+    for word in dictionary.words() {
+        let new_letters = coverage(word);
+        if new_letters > best.map_or(0, |(_, best_letters)| best_letters) {
+            best = Some((word, new_letters));
+        }
+    }
+    best.map(|(word, _)| word.to_string())
+}
+
+/// Write `your_guess` and `optimal_guess` side by side on one line, each letter
+/// painted with the style of its [Match] in the default [ColorTheme]:
+/// `Your guess: [row] | Optimal: [row]`.
+pub fn display_comparison(
+    your_guess: &TurnInput,
+    optimal_guess: &TurnInput,
+    writer: &mut impl Write,
+) -> io::Result<()> {
+    write!(writer, "Your guess: ")?;
+    write_row(writer, your_guess, ColorTheme::default())?;
+    write!(writer, " | Optimal: ")?;
+    write_row(writer, optimal_guess, ColorTheme::default())?;
+    writeln!(writer)
+}
+
+/// [display_comparison] of each guess played in `wordle` with the guess `solver`
+/// would have played knowing the outcome of the previous guesses.
+///
+/// Guesses for which `solver` has no word, like when the dictionary of `wordle`
+/// cannot list its words, are compared with `-`.
+///
+/// ```
+/// use wordler::dictionary::EnglishDictionary;
+/// use wordler::wordle::Wordle;
+/// use wordler::wordle::analysis::post_game_analysis;
+/// use wordler::wordle::solver::FrequencyStrategy;
+///
+/// let dictionary = EnglishDictionary::from_slice(&["CRANE", "TRACE", "JUMPY"]);
+/// let mut wordle = Wordle::new_with_word(&dictionary, "CRANE").unwrap();
+/// let _ = wordle.play("jumpy").unwrap();
+/// let _ = wordle.play("crane").unwrap();
+///
+/// let mut analysis = Vec::new();
+/// post_game_analysis(&wordle, &FrequencyStrategy, &mut analysis).unwrap();
+/// assert_eq!(String::from_utf8(analysis).unwrap().lines().count(), 2);
+/// ```
+pub fn post_game_analysis(
+    wordle: &Wordle,
+    solver: &dyn SolveStrategy,
+    writer: &mut impl Write,
+) -> io::Result<()> {
+    let words = wordle.dictionary.words();
+    for attempt in 0..wordle.attempts_made() as usize {
+        let your_guess = &wordle.guesses[attempt];
+        let constraints = Constraints::from_guesses(&wordle.guesses[..attempt]);
+        let remaining: Vec<&str> = words
+            .iter()
+            .copied()
+            .filter(|word| constraints.is_satisfied_by(word))
+            .collect();
+        let optimal = solver.next_guess(&constraints, &remaining).to_uppercase();
+        match evaluate_guess(&optimal, &wordle.word) {
+            Ok(matches) => display_comparison(your_guess, &turn_input(&optimal, matches), writer)?,
+            Err(_) => {
+                write!(writer, "Your guess: ")?;
+                write_row(writer, your_guess, ColorTheme::default())?;
+                writeln!(writer, " | Optimal: -")?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn write_row(writer: &mut impl Write, turn_input: &TurnInput, theme: ColorTheme) -> io::Result<()> {
+    for input in turn_input.iter() {
+        let letter = format!(" {} ", input.letter());
+        write!(writer, "{}", theme.style(&input.match_type()).paint(letter))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dictionary::EnglishDictionary;
+    use crate::wordle::solver::FrequencyStrategy;
+
+    fn rows(rows: &[&str]) -> Vec<TurnInput> {
+        rows.iter().map(|row| row.parse().unwrap()).collect()
+    }
+
+    #[test]
+    fn test_letter_position_heatmap_skips_empty_rows() {
+        let mut guesses = rows(&["C:.,R:G,A:Y,N:.,E:G"]);
+        guesses.push(TurnInput::default());
+        let heatmap = letter_position_heatmap(&guesses);
+        assert_eq!(heatmap.iter().flatten().sum::<u32>(), 5);
+        assert_eq!(heatmap[4][(b'E' - b'A') as usize], 1);
+    }
+
+    #[test]
+    fn test_match_heatmap() {
+        let guesses = rows(&["C:.,R:G,A:Y,N:.,E:G", "T:.,R:G,E:Y,A:Y,D:."]);
+        assert_eq!(
+            match_heatmap(&guesses),
+            [[0, 0, 2], [2, 0, 0], [0, 2, 0], [0, 1, 1], [1, 0, 1]]
+        );
+        assert_eq!(match_heatmap(&[TurnInput::default()]), [[0; 3]; 5]);
+    }
+
+    #[test]
+    fn test_common_letters() {
+        let guesses = rows(&[
+            "S:.,L:.,A:Y,T:.,E:.",
+            "A:G,U:.,D:.,I:Y,O:.",
+            "A:G,D:.,M:.,I:Y,T:.",
+        ]);
+        assert_eq!(
+            common_absent_letters(&guesses)[..3],
+            [('D', 2), ('T', 2), ('E', 1)]
+        );
+        assert_eq!(common_present_letters(&guesses), vec![('I', 2), ('A', 1)]);
+        assert!(common_present_letters(&[TurnInput::default()]).is_empty());
+    }
+
+    #[test]
+    fn test_optimal_remaining_guess_none() {
+        let dictionary = EnglishDictionary::from_slice(&["TRACE", "CRATE"]);
+        let guesses = rows(&["T:.,R:G,A:Y,C:.,E:G"]);
+        assert_eq!(optimal_remaining_guess(&guesses, &dictionary), None);
+        assert_eq!(
+            optimal_remaining_guess(&[], &dictionary).as_deref(),
+            Some("TRACE")
+        );
+    }
+
+    #[test]
+    fn test_post_game_analysis() {
+        let dictionary = EnglishDictionary::from_slice(&["CRANE", "TRACE", "JUMPY"]);
+        let mut wordle = Wordle::new_with_word(&dictionary, "TRACE").unwrap();
+        let _ = wordle.play("jumpy").unwrap();
+        let _ = wordle.play("crane").unwrap();
+
+        let mut analysis = Vec::new();
+        post_game_analysis(&wordle, &FrequencyStrategy, &mut analysis).unwrap();
+        let analysis = String::from_utf8(analysis).unwrap();
+        let lines: Vec<&str> = analysis.lines().collect();
+        assert_eq!(lines.len(), 2);
+        for line in lines {
+            let (yours, optimal) = line.split_once(" | ").unwrap();
+            assert!(yours.starts_with("Your guess: "));
+            assert!(optimal.starts_with("Optimal: "));
+        }
+    }
+
+    #[test]
+    fn test_post_game_analysis_without_words() {
+        struct UnlistedDict;
+        impl Dictionary for UnlistedDict {
+            fn random_word(&self) -> &str {
+                "CRANE"
+            }
+
+            fn is_valid_word(&self, word: &str) -> bool {
+                ["CRANE", "TRACE"].contains(&word)
+            }
+        }
+
+        let mut wordle = Wordle::new_with_word(&UnlistedDict, "CRANE").unwrap();
+        let _ = wordle.play("trace").unwrap();
+        let mut analysis = Vec::new();
+        post_game_analysis(&wordle, &FrequencyStrategy, &mut analysis).unwrap();
+        assert!(String::from_utf8(analysis)
+            .unwrap()
+            .ends_with(" | Optimal: -\n"));
+    }
+
+    #[test]
+    fn test_print_heatmap_widens_columns() {
+        let mut heatmap = [[0; 26]; 5];
+        heatmap[2][0] = 12;
+        let mut table = Vec::new();
+        print_heatmap(&heatmap, &mut table).unwrap();
+        assert_eq!(
+            String::from_utf8(table).unwrap(),
+            "  |  1  2  3  4  5\n--+---------------\nA |  0  0 12  0  0\n"
+        );
+    }
+}
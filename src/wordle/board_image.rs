@@ -0,0 +1,159 @@
+//! Rendering of a [Wordle] board as a PNG image, like the NYT share images.
+//!
+use super::{Match, TurnInput, Wordle};
+use fontdue::{Font, FontSettings};
+use image::{ImageError, ImageFormat, ImageOutputFormat, Rgb, RgbImage};
+use std::io::Cursor;
+use std::path::Path;
+use std::sync::OnceLock;
+
+/// Font of the letters, see assets/DejaVuSans-Bold.LICENSE.
+const FONT: &[u8] = include_bytes!("../../assets/DejaVuSans-Bold.ttf");
+
+static PARSED_FONT: OnceLock<Font> = OnceLock::new();
+
+/// Side of each square in pixels.
+const TILE: u32 = 52;
+/// Space between squares and around the board in pixels.
+const GAP: u32 = 5;
+/// Size of the letters in pixels.
+const LETTER_SIZE: f32 = 32.0;
+
+const BACKGROUND: Rgb<u8> = Rgb([255, 255, 255]);
+const EMPTY_OUTLINE: Rgb<u8> = Rgb([211, 214, 218]);
+const LETTER: Rgb<u8> = Rgb([255, 255, 255]);
+
+fn tile_color(mch: Match) -> Rgb<u8> {
+    match mch {
+        Match::ExactLocation => Rgb([106, 170, 100]),
+        Match::PresentInWord => Rgb([201, 180, 88]),
+        Match::AbsentInWord => Rgb([120, 124, 126]),
+    }
+}
+
+impl Wordle<'_> {
+    /// Save the board as a PNG image at `path`, see [Wordle::board_image_bytes].
+    pub fn export_board_image(&self, path: impl AsRef<Path>) -> Result<(), ImageError> {
+        self.board_image().save_with_format(path, ImageFormat::Png)
+    }
+
+    /// The board as PNG encoded bytes: a row of 5 squares of 52×52 pixels per
+    /// attempt, colored by [Match] with the letter centered, and grey outlines
+    /// for the rows not played yet.
+    pub fn board_image_bytes(&self) -> Result<Vec<u8>, ImageError> {
+        let mut bytes = Vec::new();
+        self.board_image()
+            .write_to(&mut Cursor::new(&mut bytes), ImageOutputFormat::Png)?;
+        Ok(bytes)
+    }
+
+    fn board_image(&self) -> RgbImage {
+        let rows = self.guesses.len() as u32;
+        let mut image = RgbImage::from_pixel(
+            GAP + 5 * (TILE + GAP),
+            GAP + rows * (TILE + GAP),
+            BACKGROUND,
+        );
+        for (row, turn_input) in self.guesses.iter().enumerate() {
+            draw_row(&mut image, GAP + row as u32 * (TILE + GAP), turn_input);
+        }
+        image
+    }
+}
+
+fn draw_row(image: &mut RgbImage, top: u32, turn_input: &TurnInput) {
+    for (column, input) in turn_input.iter().enumerate() {
+        let left = GAP + column as u32 * (TILE + GAP);
+        if turn_input.is_empty() {
+            draw_outline(image, left, top);
+        } else {
+            fill(image, left, top, tile_color(input.match_type()));
+            draw_letter(image, left, top, input.letter().to_ascii_uppercase());
+        }
+    }
+}
+
+fn fill(image: &mut RgbImage, left: u32, top: u32, color: Rgb<u8>) {
+    for y in top..top + TILE {
+        for x in left..left + TILE {
+            image.put_pixel(x, y, color);
+        }
+    }
+}
+
+fn draw_outline(image: &mut RgbImage, left: u32, top: u32) {
+    const BORDER: u32 = 2;
+    for y in top..top + TILE {
+        for x in left..left + TILE {
+            let on_border = x < left + BORDER
+                || x >= left + TILE - BORDER
+                || y < top + BORDER
+                || y >= top + TILE - BORDER;
+            if on_border {
+                image.put_pixel(x, y, EMPTY_OUTLINE);
+            }
+        }
+    }
+}
+
+/// Blend the glyph of `letter` over the square, centering its bounding box.
+fn draw_letter(image: &mut RgbImage, left: u32, top: u32, letter: char) {
+    let font = PARSED_FONT.get_or_init(|| {
+        Font::from_bytes(FONT, FontSettings::default()).expect("bundled font is valid")
+    });
+    let (metrics, coverage) = font.rasterize(letter, LETTER_SIZE);
+    let (width, height) = (metrics.width as u32, metrics.height as u32);
+    if width > TILE || height > TILE {
+        return;
+    }
+    let x0 = left + (TILE - width) / 2;
+    let y0 = top + (TILE - height) / 2;
+    for (i, &alpha) in coverage.iter().enumerate() {
+        let (x, y) = (x0 + i as u32 % width, y0 + i as u32 / width);
+        let pixel = image.get_pixel_mut(x, y);
+        for (channel, letter_channel) in pixel.0.iter_mut().zip(LETTER.0) {
+            *channel = ((letter_channel as u32 * alpha as u32
+                + *channel as u32 * (255 - alpha as u32))
+                / 255) as u8;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dictionary::EnglishDictionary;
+
+    #[test]
+    fn test_board_image() {
+        let dictionary = EnglishDictionary::from_slice(&["ARIEL", "DREAM"]);
+        let mut wordle = Wordle::new_with_word(&dictionary, "ARIEL").unwrap();
+        let _ = wordle.play("dream").unwrap();
+        let image = wordle.board_image();
+        assert_eq!(image.dimensions(), (290, 347));
+
+        // Corner of each square: D is absent, R exact, E and A present.
+        let corner = |row: u32, column: u32| {
+            *image.get_pixel(GAP + column * (TILE + GAP), GAP + row * (TILE + GAP))
+        };
+        assert_eq!(corner(0, 0), tile_color(Match::AbsentInWord));
+        assert_eq!(corner(0, 1), tile_color(Match::ExactLocation));
+        assert_eq!(corner(0, 2), tile_color(Match::PresentInWord));
+        assert_eq!(corner(1, 0), EMPTY_OUTLINE);
+
+        // The white letter is drawn inside the grey square of D.
+        let letter_pixels = (GAP..GAP + TILE)
+            .flat_map(|y| (GAP..GAP + TILE).map(move |x| (x, y)))
+            .filter(|&(x, y)| *image.get_pixel(x, y) == LETTER)
+            .count();
+        assert!(letter_pixels > 0);
+    }
+
+    #[test]
+    fn test_board_image_bytes_is_png() {
+        let dictionary = EnglishDictionary::from_slice(&["ARIEL"]);
+        let wordle = Wordle::new_with_word(&dictionary, "ARIEL").unwrap();
+        let bytes = wordle.board_image_bytes().unwrap();
+        assert!(bytes.starts_with(b"\x89PNG"));
+    }
+}
@@ -0,0 +1,483 @@
+//! Functions to evaluate guesses against an answer without a [Wordle](super::Wordle) game.
+//!
+use super::{Match, WordleError};
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+thread_local! {
+    static EVALUATION_CACHE: RefCell<HashMap<(String, String), [Match; 5]>> =
+        RefCell::new(HashMap::new());
+}
+
+/// Compute the [Match] of each letter of `guess` against `answer`.
+///
+/// Both words must have exactly 5 ASCII letters, comparison is case-insensitive.
+/// Repeated letters in `guess` are marked [Match::PresentInWord] only as many
+/// times as they occur in `answer`, exact matches taking precedence.
+///
+/// ```
+/// use wordler::wordle::Match;
+/// use wordler::wordle::eval::evaluate_guess;
+///
+/// let matches = evaluate_guess("greed", "glide").unwrap();
+/// assert_eq!(matches[0], Match::ExactLocation);
+/// assert_eq!(matches[2], Match::PresentInWord);
+/// assert_eq!(matches[3], Match::AbsentInWord);
+/// ```
+// the answer is left out of the span so logs don't reveal it
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "debug", skip(answer))
+)]
+pub fn evaluate_guess(guess: &str, answer: &str) -> Result<[Match; 5], WordleError> {
+    let guess = to_letters(guess)?;
+    let answer = to_letters(answer)?;
+    let mut matches = [Match::AbsentInWord; 5];
+
+    let mut answer_letter_count = [0_u8; 26];
+    for ch in answer {
+        answer_letter_count[(ch - b'A') as usize] += 1
+    }
+
+    // first process exact matches
+    for (idx, (guess_ch, answer_ch)) in guess.iter().zip(answer.iter()).enumerate() {
+        if guess_ch == answer_ch {
+            matches[idx] = Match::ExactLocation;
+            answer_letter_count[(guess_ch - b'A') as usize] -= 1;
+        }
+    }
+
+    // process remaining letters (not present in word, or present in word)
+    for (mch, ch) in matches.iter_mut().zip(guess) {
+        let index_in_count = (ch - b'A') as usize;
+        if *mch != Match::ExactLocation && answer_letter_count[index_in_count] > 0 {
+            *mch = Match::PresentInWord;
+            answer_letter_count[index_in_count] -= 1;
+        }
+    }
+
+    #[cfg(feature = "tracing")]
+    for (position, (ch, mch)) in guess.iter().zip(matches.iter()).enumerate() {
+        tracing::debug!("letter {} at position {}: {:?}", *ch as char, position, mch);
+    }
+
+    Ok(matches)
+}
+
+/// [evaluate_guess] memoized in `cache`, keyed by `(guess, answer)` as given.
+///
+/// Errors are not cached.
+pub fn evaluate_guess_cached(
+    guess: &str,
+    answer: &str,
+    cache: &mut HashMap<(String, String), [Match; 5]>,
+) -> Result<[Match; 5], WordleError> {
+    let key = (guess.to_string(), answer.to_string());
+    if let Some(matches) = cache.get(&key) {
+        return Ok(*matches);
+    }
+    let matches = evaluate_guess(guess, answer)?;
+    cache.insert(key, matches);
+    Ok(matches)
+}
+
+/// [evaluate_guess_cached] with a cache local to the current thread.
+///
+/// The cache is never cleared, it grows with each distinct `(guess, answer)` pair.
+pub fn evaluate_guess_tl(guess: &str, answer: &str) -> Result<[Match; 5], WordleError> {
+    EVALUATION_CACHE.with(|cache| evaluate_guess_cached(guess, answer, &mut cache.borrow_mut()))
+}
+
+/// Evaluate each of `guesses` against `answer`, see [evaluate_guess].
+pub fn compare_all(guesses: &[&str], answer: &str) -> Result<Vec<[Match; 5]>, WordleError> {
+    guesses
+        .iter()
+        .map(|guess| evaluate_guess(guess, answer))
+        .collect()
+}
+
+/// Index of the first of `guesses` which is the `answer`, if any.
+///
+/// All guesses are validated, so an invalid guess after the winning one is still an error.
+pub fn winning_guess_index(guesses: &[&str], answer: &str) -> Result<Option<usize>, WordleError> {
+    Ok(compare_all(guesses, answer)?.iter().position(is_solved))
+}
+
+/// Check if all of `result` are [Match::ExactLocation], i.e. the guess is the answer.
+pub fn is_solved(result: &[Match; 5]) -> bool {
+    exact_match_count(result) == result.len()
+}
+
+/// Number of [Match::ExactLocation] in `result`.
+pub fn exact_match_count(result: &[Match; 5]) -> usize {
+    result
+        .iter()
+        .filter(|mch| **mch == Match::ExactLocation)
+        .count()
+}
+
+/// Check if `word` reads the same backwards, ignoring case, like `LEVEL` or `Civic`.
+pub fn is_palindrome(word: &str) -> bool {
+    let letters: Vec<char> = word.chars().flat_map(char::to_uppercase).collect();
+    letters.iter().eq(letters.iter().rev())
+}
+
+/// Bits of information learned by getting `result` for `guess`, when any of
+/// `remaining` words could be the answer: `-log2(group_size / remaining.len())`
+/// where `group_size` is the number of `remaining` words giving the same result.
+///
+/// 0 when `remaining` is empty, infinite when no word of `remaining` gives `result`.
+///
+/// ```
+/// use wordler::wordle::eval::{evaluate_guess, information_bits};
+///
+/// let remaining = ["CRANE", "TRACE", "TRICE", "JUMPY"];
+/// let result = evaluate_guess("TRACE", "JUMPY").unwrap();
+/// // only JUMPY gives this result, a quarter of the words
+/// assert_eq!(information_bits("TRACE", &result, &remaining), 2.0);
+/// ```
+pub fn information_bits(guess: &str, result: &[Match; 5], remaining: &[&str]) -> f64 {
+    if remaining.is_empty() {
+        return 0.0;
+    }
+    let group_size = remaining
+        .iter()
+        .filter(|word| evaluate_guess(guess, word).is_ok_and(|matches| matches == *result))
+        .count();
+    -(group_size as f64 / remaining.len() as f64).log2()
+}
+
+/// `answers` grouped by the outcome of `guess` against them, answers which are
+/// not 5 ASCII letters are skipped.
+///
+/// ```
+/// use wordler::wordle::eval::{evaluate_guess, group_by_outcome};
+///
+/// let groups = group_by_outcome("CRANE", &["TRACE", "CRATE", "JUMPY", "PUDGY"]);
+/// assert_eq!(groups.len(), 3);
+/// let absent = evaluate_guess("CRANE", "JUMPY").unwrap().to_vec();
+/// assert_eq!(groups[&absent], vec!["JUMPY", "PUDGY"]);
+/// ```
+pub fn group_by_outcome<'a>(guess: &str, answers: &'a [&str]) -> HashMap<Vec<Match>, Vec<&'a str>> {
+    let mut groups: HashMap<Vec<Match>, Vec<&str>> = HashMap::new();
+    for answer in answers {
+        if let Ok(matches) = evaluate_guess(guess, answer) {
+            groups.entry(matches.to_vec()).or_default().push(answer);
+        }
+    }
+    groups
+}
+
+/// Shannon entropy, in bits, of the outcomes of `guess` against each of `answers`,
+/// i.e. the information expected from playing `guess`. 0 when `answers` is empty.
+///
+/// ```
+/// use wordler::wordle::eval::outcome_entropy;
+///
+/// // a different outcome for each of 4 answers
+/// assert_eq!(outcome_entropy("CRANE", &["CRANE", "TRACE", "CRATE", "JUMPY"]), 2.0);
+/// ```
+pub fn outcome_entropy(guess: &str, answers: &[&str]) -> f64 {
+    let outcomes = outcome_counts(guess, answers);
+    let total = outcomes.iter().sum::<usize>() as f64;
+    outcomes
+        .iter()
+        .filter(|count| **count > 0)
+        .map(|count| {
+            let p = *count as f64 / total;
+            -p * p.log2()
+        })
+        .sum()
+}
+
+/// Expected number of `answers` left after playing `guess`, when each of them
+/// is equally likely to be the actual answer: the sum of the squared size of each
+/// [group_by_outcome] over the number of answers. 0 when `answers` is empty.
+///
+/// ```
+/// use wordler::wordle::eval::expected_remaining;
+///
+/// // groups of 1 and 3 answers
+/// assert_eq!(expected_remaining("JUMPY", &["JUMPY", "CRANE", "TRACE", "CRATE"]), 2.5);
+/// ```
+pub fn expected_remaining(guess: &str, answers: &[&str]) -> f64 {
+    let outcomes = outcome_counts(guess, answers);
+    let total = outcomes.iter().sum::<usize>();
+    if total == 0 {
+        return 0.0;
+    }
+    outcomes.iter().map(|count| count * count).sum::<usize>() as f64 / total as f64
+}
+
+/// Number of `answers` giving each outcome of `guess`, indexed by [pattern_to_index].
+pub(super) fn outcome_counts(guess: &str, answers: &[&str]) -> [usize; PATTERN_COUNT] {
+    let mut outcomes = [0_usize; PATTERN_COUNT];
+    for answer in answers {
+        if let Ok(matches) = evaluate_guess(guess, answer) {
+            outcomes[pattern_to_index(&matches) as usize] += 1;
+        }
+    }
+    outcomes
+}
+
+/// Emoji squares of `matches`, see [TurnInput::to_emoji_string](super::TurnInput::to_emoji_string).
+///
+/// ```
+/// use wordler::wordle::eval::{evaluate_guess, matches_to_emoji};
+///
+/// let matches = evaluate_guess("greed", "glide").unwrap();
+/// assert_eq!(matches_to_emoji(&matches), "🟩⬛🟨⬛🟨");
+/// ```
+pub fn matches_to_emoji(matches: &[Match; 5]) -> String {
+    matches
+        .iter()
+        .map(|mch| match mch {
+            Match::ExactLocation => '🟩',
+            Match::PresentInWord => '🟨',
+            Match::AbsentInWord => '⬛',
+        })
+        .collect()
+}
+
+/// `G`, `Y` and `.` notation of `matches`, see [TurnInput::to_pattern_string](super::TurnInput::to_pattern_string).
+pub fn matches_to_pattern(matches: &[Match; 5]) -> String {
+    matches
+        .iter()
+        .map(|mch| match mch {
+            Match::ExactLocation => 'G',
+            Match::PresentInWord => 'Y',
+            Match::AbsentInWord => '.',
+        })
+        .collect()
+}
+
+/// Sum of `matches` counting 2 for [Match::ExactLocation], 1 for [Match::PresentInWord]
+/// and 0 for [Match::AbsentInWord], from 0 to 10 for the answer.
+pub fn matches_to_score(matches: &[Match; 5]) -> u8 {
+    matches.iter().map(Match::strength).sum()
+}
+
+/// Number of distinct outcomes of a guess, 3 [Match]es for each of 5 letters.
+pub const PATTERN_COUNT: usize = 243;
+
+/// Every outcome of a guess, ordered by [pattern_to_index].
+pub fn all_patterns() -> Vec<[Match; 5]> {
+    (0..PATTERN_COUNT as u8).map(index_to_pattern).collect()
+}
+
+/// Index of `pattern` in 0..243, reading it as a base 3 number with [Match::AbsentInWord]
+/// as 0 and [Match::ExactLocation] as 2, the first letter most significant.
+///
+/// ```
+/// use wordler::wordle::Match;
+/// use wordler::wordle::eval::{index_to_pattern, pattern_to_index};
+///
+/// assert_eq!(pattern_to_index(&[Match::AbsentInWord; 5]), 0);
+/// assert_eq!(pattern_to_index(&[Match::ExactLocation; 5]), 242);
+/// assert_eq!(index_to_pattern(242), [Match::ExactLocation; 5]);
+/// ```
+pub fn pattern_to_index(pattern: &[Match; 5]) -> u8 {
+    pattern.iter().fold(0, |index, mch| index * 3 + *mch as u8)
+}
+
+/// The pattern at `index`, inverse of [pattern_to_index].
+///
+/// Panics if `index` is not below 243.
+pub fn index_to_pattern(index: u8) -> [Match; 5] {
+    assert!(
+        (index as usize) < PATTERN_COUNT,
+        "Pattern index {} out of range",
+        index
+    );
+    let mut pattern = [Match::AbsentInWord; 5];
+    let mut rest = index;
+    for mch in pattern.iter_mut().rev() {
+        *mch = match rest % 3 {
+            0 => Match::AbsentInWord,
+            1 => Match::PresentInWord,
+            _ => Match::ExactLocation,
+        };
+        rest /= 3;
+    }
+    pattern
+}
+
+fn to_letters(word: &str) -> Result<[u8; 5], WordleError> {
+    let letters: [u8; 5] = word
+        .as_bytes()
+        .try_into()
+        .map_err(|_| WordleError::InvalidWord(word.to_string()))?;
+    if !letters.iter().all(u8::is_ascii_alphabetic) {
+        return Err(WordleError::InvalidWord(word.to_string()));
+    }
+    Ok(letters.map(|ch| ch.to_ascii_uppercase()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_outcome_entropy() {
+        // GREED has a different outcome against each word, JUMPY the same
+        let remaining = ["JUMPY", "GREED", "GLIDE", "EDGER", "ELITE"];
+        assert_eq!(outcome_entropy("GREED", &remaining), 5_f64.log2());
+        assert_eq!(outcome_entropy("JUMPY", &["CRANE", "SLATE"]), 0.0);
+        assert_eq!(outcome_entropy("JUMPY", &[]), 0.0);
+    }
+
+    #[test]
+    fn test_group_by_outcome() {
+        let answers = ["GREED", "EDGER", "JUMPY", "TOO LONG"];
+        let groups = group_by_outcome("GREED", &answers);
+        assert_eq!(groups.values().map(Vec::len).sum::<usize>(), 3);
+        assert_eq!(groups[&vec![Match::ExactLocation; 5]], vec!["GREED"]);
+        assert_eq!(expected_remaining("GREED", &answers), 1.0);
+        assert_eq!(expected_remaining("GREED", &[]), 0.0);
+    }
+
+    #[test]
+    fn test_compare_all() {
+        let results = compare_all(&["KELLY", "truly"], "TRULY").unwrap();
+        assert_eq!(results.len(), 2);
+        assert_eq!(
+            results[0],
+            [
+                Match::AbsentInWord,
+                Match::AbsentInWord,
+                Match::AbsentInWord,
+                Match::ExactLocation,
+                Match::ExactLocation,
+            ]
+        );
+        assert_eq!(results[1], [Match::ExactLocation; 5]);
+
+        assert!(compare_all(&["KELLY", "TRUE"], "TRULY").is_err());
+    }
+
+    #[test]
+    fn test_is_solved() {
+        assert!(is_solved(&[Match::ExactLocation; 5]));
+        let matches = evaluate_guess("GREED", "EDGER").unwrap();
+        assert!(!is_solved(&matches));
+        assert_eq!(exact_match_count(&matches), 1);
+        assert_eq!(
+            exact_match_count(&evaluate_guess("KELLY", "TRULY").unwrap()),
+            2
+        );
+    }
+
+    #[test]
+    fn test_is_palindrome() {
+        assert!(is_palindrome("LEVEL"));
+        assert!(is_palindrome("Civic"));
+        assert!(is_palindrome("ABBA"));
+        assert!(is_palindrome(""));
+        assert!(!is_palindrome("CRANE"));
+        assert!(!is_palindrome("LEVER"));
+    }
+
+    #[test]
+    fn test_information_bits() {
+        let remaining = ["CRANE", "TRACE", "TRICE", "JUMPY"];
+        let solved = [Match::ExactLocation; 5];
+        assert_eq!(information_bits("TRACE", &solved, &remaining), 2.0);
+        assert_eq!(information_bits("JUMPY", &solved, &[]), 0.0);
+        // every word gives the same result, nothing learned
+        let result = evaluate_guess("KELLY", "CRANE").unwrap();
+        assert_eq!(information_bits("KELLY", &result, &remaining[..2]), 0.0);
+        assert_eq!(
+            information_bits("KELLY", &solved, &remaining),
+            f64::INFINITY
+        );
+    }
+
+    #[test]
+    fn test_evaluate_guess_cached() {
+        let mut cache = HashMap::new();
+        let expected = evaluate_guess("GREED", "EDGER").unwrap();
+        assert_eq!(
+            evaluate_guess_cached("GREED", "EDGER", &mut cache).unwrap(),
+            expected
+        );
+        assert_eq!(cache.len(), 1);
+        assert_eq!(
+            evaluate_guess_cached("GREED", "EDGER", &mut cache).unwrap(),
+            expected
+        );
+        assert_eq!(cache.len(), 1);
+
+        assert!(evaluate_guess_cached("GREE", "EDGER", &mut cache).is_err());
+        assert_eq!(cache.len(), 1);
+
+        assert_eq!(evaluate_guess_tl("GREED", "EDGER").unwrap(), expected);
+        assert_eq!(evaluate_guess_tl("GREED", "EDGER").unwrap(), expected);
+    }
+
+    #[cfg(feature = "tracing")]
+    #[test]
+    #[tracing_test::traced_test]
+    fn test_evaluate_guess_traces() {
+        evaluate_guess("KELLY", "TRULY").unwrap();
+        assert!(logs_contain("letter K at position 0: AbsentInWord"));
+        assert!(logs_contain("letter L at position 3: ExactLocation"));
+        assert!(logs_contain("KELLY"));
+        assert!(!logs_contain("TRULY"));
+    }
+
+    #[test]
+    fn test_matches_to_string() {
+        let matches = evaluate_guess("GREED", "EDGER").unwrap();
+        assert_eq!(matches_to_pattern(&matches), "YYYGY");
+        assert_eq!(matches_to_emoji(&matches), "🟨🟨🟨🟩🟨");
+        assert_eq!(matches_to_score(&matches), 6);
+        assert_eq!(matches_to_score(&[Match::ExactLocation; 5]), 10);
+        assert_eq!(matches_to_score(&[Match::AbsentInWord; 5]), 0);
+    }
+
+    #[test]
+    fn test_pattern_index() {
+        let patterns = all_patterns();
+        assert_eq!(patterns.len(), 243);
+        for (index, pattern) in patterns.iter().enumerate() {
+            assert_eq!(pattern_to_index(pattern) as usize, index);
+            assert_eq!(index_to_pattern(index as u8), *pattern);
+        }
+        assert_eq!(
+            patterns
+                .iter()
+                .collect::<std::collections::HashSet<_>>()
+                .len(),
+            243
+        );
+        assert_eq!(
+            index_to_pattern(1),
+            [
+                Match::AbsentInWord,
+                Match::AbsentInWord,
+                Match::AbsentInWord,
+                Match::AbsentInWord,
+                Match::PresentInWord,
+            ]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Pattern index 243 out of range")]
+    fn test_index_to_pattern_out_of_range() {
+        index_to_pattern(243);
+    }
+
+    #[test]
+    fn test_winning_guess_index() {
+        assert_eq!(
+            winning_guess_index(&["GREED", "ELITE", "GLIDE"], "GLIDE").unwrap(),
+            Some(2)
+        );
+        assert_eq!(
+            winning_guess_index(&["GREED", "ELITE"], "GLIDE").unwrap(),
+            None
+        );
+        assert!(winning_guess_index(&["GLIDE", "GL1DE"], "GLIDE").is_err());
+    }
+}
@@ -0,0 +1,86 @@
+//! Arbitrary games for fuzz testing, with the `fuzz` feature, see the `fuzz/` directory.
+//!
+use super::{Match, Wordle};
+use crate::dictionary::EnglishDictionary;
+use arbitrary::{Arbitrary, Unstructured};
+
+/// An actual answer and up to 6 guesses of 5 uppercase letters, drawn from a
+/// small alphabet so that repeated and shared letters are common.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FuzzGame {
+    /// The actual answer.
+    pub answer: String,
+    /// The words played in order.
+    pub guesses: Vec<String>,
+}
+
+impl<'a> Arbitrary<'a> for FuzzGame {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        let answer = arbitrary_word(u)?;
+        let mut guesses = Vec::new();
+        for _ in 0..u.int_in_range(1..=6)? {
+            guesses.push(arbitrary_word(u)?);
+        }
+        Ok(FuzzGame { answer, guesses })
+    }
+}
+
+fn arbitrary_word(u: &mut Unstructured<'_>) -> arbitrary::Result<String> {
+    const LETTERS: &[u8] = b"ABCDE";
+    let mut word = String::with_capacity(5);
+    for _ in 0..5 {
+        word.push(*u.choose(LETTERS)? as char);
+    }
+    Ok(word)
+}
+
+impl FuzzGame {
+    /// Play the guesses in a [Wordle] with the actual answer until the game ends,
+    /// returning the [Match]es of each play.
+    ///
+    /// Panics if a guess is rejected, as they are all in the game's dictionary.
+    pub fn play(&self) -> Vec<[Match; 5]> {
+        let mut words: Vec<&str> = vec![self.answer.as_str()];
+        words.extend(self.guesses.iter().map(String::as_str));
+        let dictionary = EnglishDictionary::from_slice(&words);
+        let mut wordle =
+            Wordle::new_with_word(&dictionary, &self.answer).expect("answer is in the dictionary");
+
+        let mut results = Vec::new();
+        for guess in &self.guesses {
+            if wordle.is_ended() {
+                break;
+            }
+            let play_result = wordle.play(guess).expect("guess is in the dictionary");
+            results.push(
+                play_result
+                    .turn_input()
+                    .iter()
+                    .map(|input| input.match_type())
+                    .collect::<Vec<_>>()
+                    .try_into()
+                    .expect("5 letters"),
+            );
+        }
+        results
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wordle::eval::evaluate_guess;
+
+    #[test]
+    fn test_fuzz_game_agrees_with_evaluate_guess() {
+        let bytes: Vec<u8> = (0..=255).collect();
+        let mut u = Unstructured::new(&bytes);
+        while !u.is_empty() {
+            let game = FuzzGame::arbitrary(&mut u).unwrap();
+            assert!(game.answer.bytes().all(|b| (b'A'..=b'E').contains(&b)));
+            for (guess, matches) in game.guesses.iter().zip(game.play()) {
+                assert_eq!(evaluate_guess(guess, &game.answer).unwrap(), matches);
+            }
+        }
+    }
+}
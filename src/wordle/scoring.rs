@@ -0,0 +1,83 @@
+//! Scoring of finished games following the New York Times Wordle statistics.
+//!
+
+/// Points for a game: 6 when won in 1 attempt down to 1 when won in 6 attempts,
+/// and 0 when lost or won in more than 6 attempts. Playing in `hard_mode`
+/// doubles the score.
+///
+/// ```
+/// use wordler::wordle::scoring::nyt_score;
+///
+/// assert_eq!(nyt_score(1, true, false), 6);
+/// assert_eq!(nyt_score(4, true, true), 6);
+/// assert_eq!(nyt_score(6, false, true), 0);
+/// ```
+pub fn nyt_score(attempts: u8, won: bool, hard_mode: bool) -> u32 {
+    let score = match (won, attempts) {
+        (true, 1..=6) => 7 - attempts as u32,
+        _ => 0,
+    };
+    if hard_mode {
+        score * 2
+    } else {
+        score
+    }
+}
+
+/// Label shown at the end of a game, from "Genius" when won in 1 attempt to
+/// "Phew" when won in 6 or more, or "Better luck next time" when lost.
+///
+/// ```
+/// use wordler::wordle::scoring::score_label;
+///
+/// assert_eq!(score_label(3, true), "Impressive");
+/// assert_eq!(score_label(6, false), "Better luck next time");
+/// ```
+pub fn score_label(attempts: u8, won: bool) -> &'static str {
+    if !won {
+        return "Better luck next time";
+    }
+    match attempts {
+        0 | 1 => "Genius",
+        2 => "Magnificent",
+        3 => "Impressive",
+        4 => "Splendid",
+        5 => "Great",
+        _ => "Phew",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nyt_score() {
+        let scores: Vec<u32> = (1..=7)
+            .map(|attempts| nyt_score(attempts, true, false))
+            .collect();
+        assert_eq!(scores, vec![6, 5, 4, 3, 2, 1, 0]);
+        assert_eq!(nyt_score(6, true, true), 2);
+        assert_eq!(nyt_score(2, false, false), 0);
+    }
+
+    #[test]
+    fn test_score_label() {
+        let labels: Vec<&str> = (1..=7)
+            .map(|attempts| score_label(attempts, true))
+            .collect();
+        assert_eq!(
+            labels,
+            vec![
+                "Genius",
+                "Magnificent",
+                "Impressive",
+                "Splendid",
+                "Great",
+                "Phew",
+                "Phew"
+            ]
+        );
+        assert_eq!(score_label(1, false), "Better luck next time");
+    }
+}
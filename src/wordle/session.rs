@@ -0,0 +1,559 @@
+//! Statistics over the games played in a session.
+//!
+use super::eval::is_solved;
+use super::{Match, ParseError, PlayResult, Wordle, WordleError};
+use std::fmt::Display;
+#[cfg(feature = "serde_json")]
+use std::path::Path;
+use std::time::Duration;
+
+/// Width of the statistics panel, see [GameSession]'s `Display`.
+const PANEL_WIDTH: usize = 80;
+/// Width taken by the labels around each bar of the guess distribution.
+const BAR_LABEL_WIDTH: usize = 20;
+
+/// Outcome of a single game.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GameResult {
+    /// Whether the actual answer was guessed.
+    pub won: bool,
+    /// Number of attempts played, 1 to 6.
+    pub attempts_used: u8,
+    /// Time taken by all guesses, when known.
+    pub time: Option<Duration>,
+    /// Identifier of the game, see [Wordle::game_id].
+    pub game_id: Option<u64>,
+}
+
+#[cfg(feature = "serde_json")]
+impl GameResult {
+    /// JSON object of this result with `won`, `attempts_used`, and when known
+    /// `time_ms` in milliseconds and `game_id`, see [GameSession::extend_from_file].
+    pub fn to_json_value(&self) -> serde_json::Value {
+        let mut json = serde_json::json!({
+            "won": self.won,
+            "attempts_used": self.attempts_used,
+        });
+        if let Some(time) = self.time {
+            json["time_ms"] = (time.as_millis() as u64).into();
+        }
+        if let Some(game_id) = self.game_id {
+            json["game_id"] = game_id.into();
+        }
+        json
+    }
+
+    /// Parse a JSON object in the format of [GameResult::to_json_value].
+    pub fn from_json_value(json: &serde_json::Value) -> Option<GameResult> {
+        Some(GameResult {
+            won: json["won"].as_bool()?,
+            attempts_used: json["attempts_used"].as_u64()?.try_into().ok()?,
+            time: match &json["time_ms"] {
+                serde_json::Value::Null => None,
+                time => Some(Duration::from_millis(time.as_u64()?)),
+            },
+            game_id: match &json["game_id"] {
+                serde_json::Value::Null => None,
+                game_id => Some(game_id.as_u64()?),
+            },
+        })
+    }
+}
+
+/// Results of the games played, with streak tracking.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct GameSession {
+    results: Vec<GameResult>,
+    current_streak: u32,
+    max_streak: u32,
+}
+
+impl GameSession {
+    /// Create an empty session.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a session from share texts of games in order played, like
+    /// players post them, see [parse_share_text].
+    ///
+    /// The time and identifier of the games are not known.
+    pub fn from_wordle_share_texts(texts: &[&str]) -> Result<GameSession, ParseError> {
+        let mut session = GameSession::new();
+        for text in texts {
+            let (_, won, attempts_used, _) = parse_share_text(text)?;
+            session.record(GameResult {
+                won,
+                attempts_used,
+                time: None,
+                game_id: None,
+            });
+        }
+        Ok(session)
+    }
+
+    /// Record the result of a game.
+    pub fn record(&mut self, result: GameResult) {
+        if result.won {
+            self.current_streak += 1;
+            self.max_streak = self.max_streak.max(self.current_streak);
+        } else {
+            self.current_streak = 0;
+        }
+        self.results.push(result);
+    }
+
+    /// Record the result of a `wordle` game which has been won or lost.
+    ///
+    /// Fails with [WordleError::GameStillInProgress] otherwise, recording nothing.
+    pub fn record_from_wordle(&mut self, wordle: &Wordle) -> Result<(), WordleError> {
+        if !wordle.is_ended() {
+            return Err(WordleError::GameStillInProgress);
+        }
+        self.record(GameResult {
+            won: wordle.is_won(),
+            attempts_used: wordle.current_attempt,
+            time: Some(wordle.total_time()),
+            game_id: Some(wordle.game_id()),
+        });
+        Ok(())
+    }
+
+    /// Record the result of a game from its last play, won or lost.
+    ///
+    /// [PlayResult::TurnResult]s are ignored, so every play of a game can be passed in.
+    pub fn record_from_play_result(&mut self, result: &PlayResult) {
+        if result.is_terminal() {
+            self.record(GameResult {
+                won: result.is_won(),
+                attempts_used: result.attempt(),
+                time: None,
+                game_id: result.game_id(),
+            });
+        }
+    }
+
+    /// Record the results of all games of `other` after the ones of this session,
+    /// to merge sessions played on different devices.
+    pub fn append(&mut self, other: GameSession) {
+        self.extend(other.results);
+    }
+
+    /// Record the results of a file with one JSON object per line,
+    /// see [GameResult::to_json_value]. Blank lines are skipped.
+    ///
+    /// Fails with [WordleError::Io] of kind `InvalidData` on the first invalid line,
+    /// recording nothing.
+    #[cfg(feature = "serde_json")]
+    pub fn extend_from_file(&mut self, path: impl AsRef<Path>) -> Result<(), WordleError> {
+        let contents = std::fs::read_to_string(path)?;
+        let results = contents
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(index, line)| {
+                serde_json::from_str(line)
+                    .ok()
+                    .and_then(|json| GameResult::from_json_value(&json))
+                    .ok_or_else(|| {
+                        std::io::Error::new(
+                            std::io::ErrorKind::InvalidData,
+                            format!("invalid game result on line {}", index + 1),
+                        )
+                    })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        self.extend(results);
+        Ok(())
+    }
+
+    /// Results of all games, in order played.
+    pub fn results(&self) -> &[GameResult] {
+        &self.results
+    }
+
+    /// Number of games played.
+    pub fn played(&self) -> usize {
+        self.results.len()
+    }
+
+    /// Number of games won.
+    pub fn won(&self) -> usize {
+        self.results.iter().filter(|result| result.won).count()
+    }
+
+    /// Percentage of games won, 0 when none played.
+    pub fn win_percentage(&self) -> f64 {
+        if self.results.is_empty() {
+            return 0.0;
+        }
+        self.won() as f64 * 100.0 / self.played() as f64
+    }
+
+    /// Number of consecutive games won up to the last one.
+    pub fn current_streak(&self) -> u32 {
+        self.current_streak
+    }
+
+    /// Longest number of consecutive games won.
+    pub fn max_streak(&self) -> u32 {
+        self.max_streak
+    }
+
+    /// Number of games won in 1 to 6 attempts.
+    pub fn guess_distribution(&self) -> [u32; 6] {
+        let mut distribution = [0; 6];
+        for result in self.results.iter().filter(|result| result.won) {
+            if (1..=6).contains(&result.attempts_used) {
+                distribution[result.attempts_used as usize - 1] += 1;
+            }
+        }
+        distribution
+    }
+}
+
+/// Game number if any, whether the game was won, attempts used and matches of each row
+/// of a shared game, see [parse_share_text].
+pub type SharedGame = (Option<u32>, bool, u8, Vec<[Match; 5]>);
+
+/// Parse the share text of a game, see [Wordle::as_share_text], into its game
+/// number, whether it was won, the attempts used and the matches of each row.
+///
+/// The game number in the header is optional, commas between thousands are accepted.
+/// Rows can use the dark 🟩🟨⬛, light ⬜ or high contrast 🟧🟦 squares, and lines
+/// after the grid, like the time taken, are ignored.
+///
+/// ```
+/// use wordler::wordle::session::parse_share_text;
+///
+/// let (number, won, attempts, rows) =
+///     parse_share_text("Wordle 451 2/6\n\n⬛🟨⬛⬛🟩\n🟩🟩🟩🟩🟩").unwrap();
+/// assert_eq!((number, won, attempts, rows.len()), (Some(451), true, 2, 2));
+/// ```
+pub fn parse_share_text(text: &str) -> Result<SharedGame, ParseError> {
+    let mut lines = text.lines().map(str::trim).filter(|line| !line.is_empty());
+    let header = lines
+        .next()
+        .ok_or_else(|| ParseError::InvalidFormat(text.to_string()))?;
+    let invalid_header = || ParseError::InvalidFormat(header.to_string());
+
+    let mut parts = header.split_whitespace();
+    if parts.next() != Some("Wordle") {
+        return Err(invalid_header());
+    }
+    let mut score = parts.next().ok_or_else(invalid_header)?;
+    let mut game_number = None;
+    if !score.contains('/') {
+        game_number = Some(
+            score
+                .replace(',', "")
+                .parse::<u32>()
+                .map_err(|_| invalid_header())?,
+        );
+        score = parts.next().ok_or_else(invalid_header)?;
+    }
+    let score = score.strip_suffix('*').unwrap_or(score);
+    let attempts = score.strip_suffix("/6").ok_or_else(invalid_header)?;
+    let won = attempts != "X";
+    let attempts: u8 = if won {
+        attempts
+            .parse()
+            .ok()
+            .filter(|attempts| (1..=6).contains(attempts))
+            .ok_or_else(invalid_header)?
+    } else {
+        6
+    };
+
+    let rows = lines
+        .skip_while(|line| !line.starts_with(is_square))
+        .take_while(|line| line.starts_with(is_square))
+        .map(parse_square_row)
+        .collect::<Result<Vec<_>, _>>()?;
+    let solved_at = rows.iter().position(is_solved);
+    let expected_solved_at = if won {
+        Some(attempts as usize - 1)
+    } else {
+        None
+    };
+    if rows.len() != attempts as usize || solved_at != expected_solved_at {
+        return Err(ParseError::InvalidFormat(text.to_string()));
+    }
+    Ok((game_number, won, attempts, rows))
+}
+
+fn is_square(ch: char) -> bool {
+    square_match(ch).is_some()
+}
+
+fn square_match(ch: char) -> Option<Match> {
+    match ch {
+        '🟩' | '🟧' => Some(Match::ExactLocation),
+        '🟨' | '🟦' => Some(Match::PresentInWord),
+        '⬛' | '⬜' => Some(Match::AbsentInWord),
+        _ => None,
+    }
+}
+
+fn parse_square_row(line: &str) -> Result<[Match; 5], ParseError> {
+    let matches: Option<Vec<Match>> = line
+        .chars()
+        // some platforms add a variation selector after each square
+        .filter(|ch| *ch != '\u{fe0f}')
+        .map(square_match)
+        .collect();
+    matches
+        .and_then(|matches| matches.try_into().ok())
+        .ok_or_else(|| ParseError::InvalidPattern(line.to_string()))
+}
+
+impl Extend<GameResult> for GameSession {
+    /// Record each of `results` in order, see [GameSession::record].
+    fn extend<T: IntoIterator<Item = GameResult>>(&mut self, results: T) {
+        for result in results {
+            self.record(result);
+        }
+    }
+}
+
+impl Display for GameSession {
+    /// Statistics panel with a bar chart of the guess distribution, 80 columns wide.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{:^width$}", "STATISTICS", width = PANEL_WIDTH)?;
+        writeln!(f, "Played: {}", self.played())?;
+        writeln!(f, "Win %: {:.0}", self.win_percentage())?;
+        writeln!(f, "Current Streak: {}", self.current_streak)?;
+        writeln!(f, "Max Streak: {}", self.max_streak)?;
+        writeln!(f)?;
+        writeln!(f, "{:^width$}", "GUESS DISTRIBUTION", width = PANEL_WIDTH)?;
+
+        let distribution = self.guess_distribution();
+        let max_count = distribution.iter().copied().max().unwrap_or(0).max(1);
+        let won = self.won().max(1);
+        let bar_width = PANEL_WIDTH - BAR_LABEL_WIDTH;
+        for (attempt, count) in distribution.iter().enumerate() {
+            let filled = (*count as usize * bar_width) / max_count as usize;
+            writeln!(
+                f,
+                "{} {}{} {} ({:.0}%)",
+                attempt + 1,
+                "▓".repeat(filled),
+                "░".repeat(bar_width - filled),
+                count,
+                *count as f64 * 100.0 / won as f64
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dictionary::EnglishDictionary;
+
+    #[test]
+    fn test_parse_share_text() {
+        let dark = "Wordle 451 4/6\n\n⬛🟨⬛⬛⬛\n⬛⬛🟨🟨⬛\n🟨🟩⬛🟩⬛\n🟩🟩🟩🟩🟩";
+        let (number, won, attempts, rows) = parse_share_text(dark).unwrap();
+        assert_eq!((number, won, attempts), (Some(451), true, 4));
+        assert_eq!(crate::wordle::eval::matches_to_pattern(&rows[2]), "YG.G.");
+
+        let light_hard_lost = "Wordle 1,234 X/6*\n\n⬜⬜🟨⬜⬜\n⬜🟩⬜⬜⬜\n\
+                               🟨🟩⬜⬜⬜\n⬜🟩🟩⬜🟨\n⬜🟩🟩🟩🟩\n⬜🟩🟩🟩🟩";
+        let (number, won, attempts, rows) = parse_share_text(light_hard_lost).unwrap();
+        assert_eq!(
+            (number, won, attempts, rows.len()),
+            (Some(1234), false, 6, 6)
+        );
+
+        let high_contrast = "Wordle 700 3/6\n\n🟦⬛⬛⬛⬛\n⬛🟧🟦⬛⬛\n🟧🟧🟧🟧🟧\n\n⏱ 1:23";
+        assert_eq!(parse_share_text(high_contrast).unwrap().2, 3);
+    }
+
+    #[test]
+    fn test_parse_share_text_errors() {
+        assert!(matches!(
+            parse_share_text("Wordle 45x 1/6\n\n🟩🟩🟩🟩🟩"),
+            Err(ParseError::InvalidFormat(_))
+        ));
+        assert!(matches!(
+            parse_share_text("Wordle 451 7/6"),
+            Err(ParseError::InvalidFormat(_))
+        ));
+        assert!(matches!(
+            parse_share_text("Wordle 451 1/6\n\n🟩🟩🟩🟩"),
+            Err(ParseError::InvalidPattern(_))
+        ));
+        // 2 rows for 1 attempt
+        assert!(matches!(
+            parse_share_text("Wordle 451 1/6\n\n⬛⬛⬛⬛⬛\n🟩🟩🟩🟩🟩"),
+            Err(ParseError::InvalidFormat(_))
+        ));
+    }
+
+    #[test]
+    fn test_from_wordle_share_texts() {
+        let dictionary = EnglishDictionary::from_slice(&["GREED", "GLIDE"]);
+        let mut wordle = Wordle::new_with_word(&dictionary, "GLIDE").unwrap();
+        let _ = wordle.play("greed").unwrap();
+        let _ = wordle.play("glide").unwrap();
+        let shared = wordle.as_share_text(Some(451), false).unwrap();
+
+        let session = GameSession::from_wordle_share_texts(&[
+            "Wordle 450 X/6\n\n⬛⬛⬛⬛⬛\n⬛⬛⬛⬛⬛\n⬛⬛⬛⬛⬛\n⬛⬛⬛⬛⬛\n⬛⬛⬛⬛⬛\n⬛⬛⬛⬛⬛",
+            &shared,
+        ])
+        .unwrap();
+        assert_eq!(session.played(), 2);
+        assert_eq!(session.current_streak(), 1);
+        assert_eq!(session.guess_distribution(), [0, 1, 0, 0, 0, 0]);
+        assert!(GameSession::from_wordle_share_texts(&["Wordle"]).is_err());
+    }
+
+    #[test]
+    fn test_share_text_without_game_number() {
+        let dictionary = EnglishDictionary::from_slice(&["GREED", "GLIDE"]);
+        let mut wordle = Wordle::new_with_word(&dictionary, "GLIDE").unwrap();
+        let _ = wordle.play("greed").unwrap();
+        let _ = wordle.play("glide").unwrap();
+        let shared = wordle.as_share_text(None, false).unwrap();
+
+        let (number, won, attempts, _) = parse_share_text(&shared).unwrap();
+        assert_eq!((number, won, attempts), (None, true, 2));
+        let session = GameSession::from_wordle_share_texts(&[&shared]).unwrap();
+        assert_eq!(session.guess_distribution(), [0, 1, 0, 0, 0, 0]);
+    }
+
+    fn session(results: &[(bool, u8)]) -> GameSession {
+        let mut session = GameSession::new();
+        for (won, attempts_used) in results {
+            session.record(GameResult {
+                won: *won,
+                attempts_used: *attempts_used,
+                time: None,
+                game_id: None,
+            });
+        }
+        session
+    }
+
+    #[test]
+    fn test_streaks() {
+        let session = session(&[(true, 3), (true, 4), (false, 6), (true, 2)]);
+        assert_eq!(session.played(), 4);
+        assert_eq!(session.win_percentage(), 75.0);
+        assert_eq!(session.current_streak(), 1);
+        assert_eq!(session.max_streak(), 2);
+        assert_eq!(session.guess_distribution(), [0, 1, 1, 1, 0, 0]);
+    }
+
+    #[test]
+    fn test_record_from_wordle() {
+        let dictionary = EnglishDictionary::from_slice(&["GREED", "GLIDE", "ELITE"]);
+        let mut session = GameSession::new();
+
+        let mut wordle = Wordle::new_with_word(&dictionary, "GLIDE").unwrap();
+        assert!(!wordle.play("GREED").unwrap().is_terminal());
+        assert!(matches!(
+            session.record_from_wordle(&wordle),
+            Err(WordleError::GameStillInProgress)
+        ));
+        assert_eq!(session.played(), 0);
+
+        assert!(wordle.play("GLIDE").unwrap().is_won());
+        session.record_from_wordle(&wordle).unwrap();
+
+        let mut wordle = Wordle::new_with_word(&dictionary, "ELITE").unwrap();
+        for _ in 0..6 {
+            let result = wordle.play("GLIDE").unwrap();
+            session.record_from_play_result(&result);
+        }
+        session.record_from_wordle(&wordle).unwrap();
+
+        let results: Vec<(bool, u8)> = session
+            .results()
+            .iter()
+            .map(|result| (result.won, result.attempts_used))
+            .collect();
+        assert_eq!(results, [(true, 2), (false, 6), (false, 6)]);
+        assert!(session.results()[0].time.is_some());
+        assert_eq!(session.results()[1].time, None);
+        let elite = crate::wordle::game_id_for_word("ELITE");
+        assert_eq!(session.results()[1].game_id, Some(elite));
+        assert_eq!(session.results()[2].game_id, Some(elite));
+        assert_eq!(session.current_streak(), 0);
+        assert_eq!(session.max_streak(), 1);
+    }
+
+    #[test]
+    fn test_append() {
+        let mut session = session(&[(true, 3), (true, 4)]);
+        session.append(self::session(&[(true, 2), (false, 6), (true, 5)]));
+        assert_eq!(session.played(), 5);
+        assert_eq!(session.max_streak(), 3);
+        assert_eq!(session.current_streak(), 1);
+
+        session.extend([GameResult {
+            won: true,
+            attempts_used: 1,
+            time: None,
+            game_id: None,
+        }]);
+        assert_eq!(session.guess_distribution(), [1, 1, 1, 1, 1, 0]);
+        assert_eq!(session.current_streak(), 2);
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[test]
+    fn test_extend_from_file() {
+        let result = GameResult {
+            won: true,
+            attempts_used: 3,
+            time: Some(Duration::from_millis(61_500)),
+            game_id: Some(u64::MAX),
+        };
+        assert_eq!(
+            GameResult::from_json_value(&result.to_json_value()),
+            Some(result)
+        );
+
+        let path = std::env::temp_dir().join(format!("wordler-session-{}", std::process::id()));
+        let lines = format!(
+            "{}\n\n{}\n",
+            result.to_json_value(),
+            r#"{"won": false, "attempts_used": 6}"#
+        );
+        std::fs::write(&path, lines).unwrap();
+        let mut session = GameSession::new();
+        session.extend_from_file(&path).unwrap();
+        assert_eq!(session.results()[0], result);
+        assert!(!session.results()[1].won && session.results()[1].time.is_none());
+
+        std::fs::write(&path, "{\"won\": true}\n").unwrap();
+        assert!(matches!(
+            session.extend_from_file(&path),
+            Err(WordleError::Io(e)) if e.kind() == std::io::ErrorKind::InvalidData
+        ));
+        assert_eq!(session.played(), 2);
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_display() {
+        let session = session(&[(true, 3), (true, 3), (true, 4), (false, 6)]);
+        let panel = session.to_string();
+        assert!(panel.contains("Played: 4"));
+        assert!(panel.contains("Win %: 75"));
+        assert!(panel.contains("Max Streak: 3"));
+        assert!(panel
+            .lines()
+            .all(|line| line.chars().count() <= PANEL_WIDTH));
+
+        let bars: Vec<&str> = panel.lines().skip_while(|l| !l.starts_with('1')).collect();
+        assert_eq!(bars.len(), 6);
+        assert!(bars[2].starts_with(&format!("3 {}", "▓".repeat(60))));
+        assert!(bars[2].ends_with(" 2 (67%)"));
+        assert!(bars[3].starts_with(&format!("4 {}░", "▓".repeat(30))));
+    }
+}
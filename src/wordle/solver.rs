@@ -0,0 +1,770 @@
+//! Building blocks for solving a [Wordle](super::Wordle) from the outcome of previous guesses.
+//!
+use super::eval::{evaluate_guess, is_solved, outcome_counts, outcome_entropy};
+use super::stats::{rank_by_coverage, rank_words};
+use super::{ColorTheme, Input, Match, TurnInput, WordleError};
+use crate::dictionary::Dictionary;
+use ansi_term::Color::RGB;
+use std::collections::BTreeMap;
+use std::fmt::Display;
+
+pub mod opening_book;
+
+/// Knowledge about the actual answer accumulated from played [TurnInput]s.
+#[derive(Clone, PartialEq)]
+pub struct Constraints {
+    /// Letter known to be at each position.
+    exact: [Option<u8>; 5],
+    /// Letters known not to be at each position.
+    excluded: [[bool; 26]; 5],
+    /// Minimum number of occurrences of each letter.
+    min_count: [u8; 26],
+    /// Maximum number of occurrences of each letter.
+    max_count: [u8; 26],
+}
+
+impl Default for Constraints {
+    fn default() -> Self {
+        Constraints {
+            exact: [None; 5],
+            excluded: [[false; 26]; 5],
+            min_count: [0; 26],
+            max_count: [5; 26],
+        }
+    }
+}
+
+impl Constraints {
+    /// Create Constraints from the given guesses.
+    pub fn from_guesses<'a>(guesses: impl IntoIterator<Item = &'a TurnInput>) -> Self {
+        let mut constraints = Constraints::default();
+        for turn_input in guesses {
+            constraints.add(turn_input);
+        }
+        constraints
+    }
+
+    /// Narrow down the constraints with outcome of another guess.
+    ///
+    /// Rows not played yet and letters other than `A` to `Z` are ignored.
+    pub fn add(&mut self, turn_input: &TurnInput) {
+        if turn_input.is_empty() {
+            return;
+        }
+        let mut found = [0_u8; 26];
+        let mut absent = [false; 26];
+
+        for (position, input) in turn_input.iter().enumerate() {
+            if !input.chr.is_ascii_uppercase() {
+                continue;
+            }
+            let index = (input.chr - b'A') as usize;
+            match input.mch {
+                Match::ExactLocation => {
+                    self.exact[position] = Some(input.chr);
+                    found[index] += 1;
+                }
+                Match::PresentInWord => {
+                    self.excluded[position][index] = true;
+                    found[index] += 1;
+                }
+                Match::AbsentInWord => {
+                    self.excluded[position][index] = true;
+                    absent[index] = true;
+                }
+            }
+        }
+
+        for index in 0..26 {
+            self.min_count[index] = self.min_count[index].max(found[index]);
+            // an absent letter means the word has no more than the ones found
+            if absent[index] {
+                self.max_count[index] = self.max_count[index].min(found[index]);
+            }
+        }
+    }
+
+    /// Describe the constraints in plain English, one per line:
+    /// letters known or excluded at each position, then letter counts,
+    /// then letters absent from the answer. Empty when nothing is known.
+    ///
+    /// ```text
+    /// Position 1: must be 'G'
+    /// Position 3: cannot be 'E'
+    /// Must contain at least 2 'E's
+    /// Must not contain: K, X, Z
+    /// ```
+    pub fn explain(&self) -> String {
+        let letter = |index: usize| (b'A' + index as u8) as char;
+        let mut lines = Vec::new();
+
+        for position in 0..5 {
+            if let Some(exact) = self.exact[position] {
+                lines.push(format!(
+                    "Position {}: must be '{}'",
+                    position + 1,
+                    exact as char
+                ));
+                continue;
+            }
+            // letters absent from the answer are listed once at the end
+            let excluded: Vec<String> = (0..26)
+                .filter(|index| self.excluded[position][*index] && self.max_count[*index] > 0)
+                .map(|index| format!("'{}'", letter(index)))
+                .collect();
+            if !excluded.is_empty() {
+                lines.push(format!(
+                    "Position {}: cannot be {}",
+                    position + 1,
+                    excluded.join(", ")
+                ));
+            }
+        }
+
+        for index in 0..26 {
+            let (min, max) = (self.min_count[index], self.max_count[index]);
+            let plural = if min > 1 { "s" } else { "" };
+            if min > 0 && min == max {
+                lines.push(format!(
+                    "Must contain exactly {} '{}'{}",
+                    min,
+                    letter(index),
+                    plural
+                ));
+            } else if min > 1 {
+                lines.push(format!(
+                    "Must contain at least {} '{}'s",
+                    min,
+                    letter(index)
+                ));
+            } else if min == 1 {
+                lines.push(format!("Must contain '{}'", letter(index)));
+            }
+        }
+
+        let absent: Vec<String> = (0..26)
+            .filter(|index| self.max_count[*index] == 0)
+            .map(|index| letter(index).to_string())
+            .collect();
+        if !absent.is_empty() {
+            lines.push(format!("Must not contain: {}", absent.join(", ")));
+        }
+
+        lines.join("\n")
+    }
+
+    /// Check if `word` can still be the actual answer.
+    pub fn is_satisfied_by(&self, word: &str) -> bool {
+        let word = word.as_bytes();
+        if word.len() != 5 || !word.iter().all(u8::is_ascii_alphabetic) {
+            return false;
+        }
+
+        let mut count = [0_u8; 26];
+        for (position, ch) in word.iter().map(u8::to_ascii_uppercase).enumerate() {
+            let index = (ch - b'A') as usize;
+            if matches!(self.exact[position], Some(exact) if exact != ch)
+                || self.excluded[position][index]
+            {
+                return false;
+            }
+            count[index] += 1;
+        }
+
+        (0..26).all(|index| {
+            count[index] >= self.min_count[index] && count[index] <= self.max_count[index]
+        })
+    }
+}
+
+/// A row of the 5 positions: the letter known at a position in green, `?` in yellow
+/// where a letter known to be in the answer is excluded, and `_` in grey otherwise.
+impl Display for Constraints {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let theme = ColorTheme::default();
+        for position in 0..5 {
+            let cell = match self.exact[position] {
+                Some(exact) => theme
+                    .style(&Match::ExactLocation)
+                    .paint(format!(" {} ", exact as char)),
+                None if (0..26)
+                    .any(|index| self.excluded[position][index] && self.min_count[index] > 0) =>
+                {
+                    theme.style(&Match::PresentInWord).paint(" ? ")
+                }
+                None => RGB(128, 128, 128).paint(" _ "),
+            };
+            write!(f, "{}", cell)?;
+        }
+        Ok(())
+    }
+}
+
+/// Letters known at each position, letters excluded at each position, and the
+/// minimum and maximum counts of each letter which differ from the defaults.
+impl std::fmt::Debug for Constraints {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let letter = |index: usize| (b'A' + index as u8) as char;
+        let excluded: Vec<String> = self
+            .excluded
+            .iter()
+            .map(|letters| {
+                (0..26)
+                    .filter(|index| letters[*index])
+                    .map(letter)
+                    .collect()
+            })
+            .collect();
+        let counts = |counts: &[u8; 26], default: u8| -> BTreeMap<char, u8> {
+            (0..26)
+                .filter(|index| counts[*index] != default)
+                .map(|index| (letter(index), counts[index]))
+                .collect()
+        };
+        f.debug_struct("Constraints")
+            .field("exact", &self.exact.map(|exact| exact.map(char::from)))
+            .field("excluded", &excluded)
+            .field("min_count", &counts(&self.min_count, 0))
+            .field("max_count", &counts(&self.max_count, 5))
+            .finish()
+    }
+}
+
+/// A hard mode rule broken by a guess, see [HardModeValidator].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HardModeViolation {
+    /// A letter revealed at its exact location must be played there again.
+    MustUseExactAt {
+        /// The revealed letter.
+        letter: char,
+        /// 0-indexed position of the letter.
+        position: usize,
+    },
+    /// A letter revealed as present in word must be played again.
+    MustInclude {
+        /// The revealed letter.
+        letter: char,
+    },
+}
+
+impl Display for HardModeViolation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HardModeViolation::MustUseExactAt { letter, position } => {
+                write!(f, "You must use '{}' in position {}", letter, position + 1)
+            }
+            HardModeViolation::MustInclude { letter } => {
+                write!(f, "You must use '{}'", letter)
+            }
+        }
+    }
+}
+
+/// Checks guesses against the hard mode rules: every revealed letter must be used
+/// in subsequent guesses, and letters at their exact location must stay there.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct HardModeValidator;
+
+impl HardModeValidator {
+    /// Check `guess` against the letters revealed in `constraints`,
+    /// reporting every rule it breaks.
+    pub fn validate(
+        &self,
+        guess: &str,
+        constraints: &Constraints,
+    ) -> Result<(), Vec<HardModeViolation>> {
+        let guess: Vec<u8> = guess.bytes().map(|b| b.to_ascii_uppercase()).collect();
+        let mut violations = Vec::new();
+
+        for (position, exact) in constraints.exact.iter().enumerate() {
+            if let Some(letter) = *exact {
+                if guess.get(position) != Some(&letter) {
+                    violations.push(HardModeViolation::MustUseExactAt {
+                        letter: letter as char,
+                        position,
+                    });
+                }
+            }
+        }
+
+        for (index, min_count) in constraints.min_count.iter().enumerate() {
+            let letter = b'A' + index as u8;
+            let count = guess.iter().filter(|ch| **ch == letter).count();
+            // a letter missing from its exact location is reported once
+            let reported = violations.iter().any(|violation| {
+                matches!(
+                    violation,
+                    HardModeViolation::MustUseExactAt { letter: l, .. } if *l == letter as char
+                )
+            });
+            if count < *min_count as usize && !reported {
+                violations.push(HardModeViolation::MustInclude {
+                    letter: letter as char,
+                });
+            }
+        }
+
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(violations)
+        }
+    }
+}
+
+/// Picks the next guess of an automated solver, see [solve_one].
+pub trait SolveStrategy {
+    /// The next word to play given what is known of the answer in `constraints`
+    /// and the `remaining` words of the dictionary satisfying them.
+    fn next_guess(&self, constraints: &Constraints, remaining: &[&str]) -> String;
+}
+
+/// Plays the remaining word which splits the other remaining words into the most
+/// evenly sized groups of outcomes, maximizing the expected information gained.
+///
+/// Every remaining word is evaluated against every other, so the first guesses
+/// on a large dictionary are slow.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct EntropyStrategy;
+
+impl SolveStrategy for EntropyStrategy {
+    fn next_guess(&self, _constraints: &Constraints, remaining: &[&str]) -> String {
+        let mut best: Option<(&str, f64)> = None;
+        for guess in remaining {
+            let entropy = outcome_entropy(guess, remaining);
+            if best.is_none_or(|(_, best_entropy)| entropy > best_entropy) {
+                best = Some((guess, entropy));
+            }
+        }
+        best.map(|(guess, _)| guess.to_string()).unwrap_or_default()
+    }
+}
+
+/// Plays the remaining word with the most common letters at their position among
+/// the remaining words, see [rank_words](super::stats::rank_words).
+#[derive(Debug, Default, Clone, Copy)]
+pub struct FrequencyStrategy;
+
+impl SolveStrategy for FrequencyStrategy {
+    fn next_guess(&self, _constraints: &Constraints, remaining: &[&str]) -> String {
+        rank_by_coverage(remaining.to_vec())
+            .first()
+            .map(|(guess, _)| guess.to_string())
+            .unwrap_or_default()
+    }
+}
+
+/// Outcome of an automated game, see [solve_one].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SolveStats {
+    /// Number of guesses played, 1 to 6.
+    pub attempts_used: u8,
+    /// Guesses played, in order.
+    pub guesses: Vec<String>,
+    /// Whether the answer was guessed within 6 attempts.
+    pub won: bool,
+}
+
+/// Play a game against `answer` with guesses from `strategy`, without a [Wordle](super::Wordle).
+///
+/// The strategy picks among the words of `dictionary` still satisfying the outcome
+/// of previous guesses, so `dictionary` must be able to list its words.
+/// Fails when `answer` is not in `dictionary` or `strategy` plays an invalid word.
+///
+/// ```
+/// use wordler::dictionary::EnglishDictionary;
+/// use wordler::wordle::solver::{solve_one, FrequencyStrategy};
+///
+/// let dictionary = EnglishDictionary::from_slice(&["CRANE", "TRACE", "TRICE", "JUMPY"]);
+/// let stats = solve_one(&dictionary, "jumpy", &FrequencyStrategy).unwrap();
+/// assert!(stats.won);
+/// assert_eq!(stats.guesses, ["TRACE", "JUMPY"]);
+/// ```
+pub fn solve_one(
+    dictionary: &dyn Dictionary,
+    answer: &str,
+    strategy: &dyn SolveStrategy,
+) -> Result<SolveStats, WordleError> {
+    let answer = answer.to_uppercase();
+    if !dictionary.is_valid_word(&answer) {
+        return Err(WordleError::NotInDictionary(answer, Vec::new()));
+    }
+
+    let mut constraints = Constraints::default();
+    let mut remaining: Vec<&str> = dictionary
+        .words()
+        .into_iter()
+        .filter(|word| constraints.is_satisfied_by(word))
+        .collect();
+    let mut guesses = Vec::new();
+
+    while guesses.len() < 6 {
+        let guess = strategy.next_guess(&constraints, &remaining).to_uppercase();
+        let matches = evaluate_guess(&guess, &answer)?;
+        constraints.add(&turn_input(&guess, matches));
+        guesses.push(guess);
+
+        if is_solved(&matches) {
+            break;
+        }
+        remaining.retain(|word| constraints.is_satisfied_by(word));
+    }
+
+    Ok(SolveStats {
+        attempts_used: guesses.len() as u8,
+        won: guesses.last() == Some(&answer),
+        guesses,
+    })
+}
+
+/// Solve every word of `dictionary` with `strategy`, see [solve_one].
+pub fn solve_all(
+    dictionary: &dyn Dictionary,
+    strategy: &dyn SolveStrategy,
+) -> Vec<(String, SolveStats)> {
+    dictionary
+        .words()
+        .into_iter()
+        .filter_map(|answer| {
+            solve_one(dictionary, answer, strategy)
+                .ok()
+                .map(|stats| (answer.to_string(), stats))
+        })
+        .collect()
+}
+
+/// The guess among the words of `all_guesses` leaving the fewest of `remaining`
+/// possible answers in the worst case, guaranteeing how much each guess narrows down.
+///
+/// Ties go to a word of `remaining`, which could win right away, then to the first word.
+/// Only `remaining` is considered when `all_guesses` cannot list its words.
+/// Empty when `remaining` is.
+pub fn minimax_best_guess(remaining: &[&str], all_guesses: &dyn Dictionary) -> String {
+    if remaining.is_empty() {
+        return String::new();
+    }
+    let mut guesses = all_guesses.words();
+    if guesses.is_empty() {
+        guesses = remaining.to_vec();
+    }
+
+    let mut best: Option<(&str, (usize, bool))> = None;
+    for guess in guesses {
+        let key = (worst_case(guess, remaining), !remaining.contains(&guess));
+        if best.is_none_or(|(_, best_key)| key < best_key) {
+            best = Some((guess, key));
+        }
+    }
+    best.map(|(guess, _)| guess.to_string()).unwrap_or_default()
+}
+
+/// Score of `word` by the frequency of its letters at their position, as given by
+/// [EnglishDictionary::letter_frequency](crate::dictionary::EnglishDictionary::letter_frequency).
+///
+/// A repeated letter only counts once, at the position where it's most common,
+/// as it reveals nothing new the second time.
+///
+/// ```
+/// use wordler::dictionary::EnglishDictionary;
+/// use wordler::wordle::solver::word_score;
+///
+/// let dictionary = EnglishDictionary::from_slice(&["GREED", "GLIDE", "EDGER"]);
+/// let frequency = dictionary.letter_frequency();
+/// assert!(word_score("GREED", &frequency) > word_score("EDGER", &frequency));
+/// ```
+pub fn word_score(word: &str, letter_freq: &[[f64; 26]; 5]) -> f64 {
+    let mut best = [None::<f64>; 26];
+    for (position, ch) in word.bytes().take(5).enumerate() {
+        if ch.is_ascii_alphabetic() {
+            let index = (ch.to_ascii_uppercase() - b'A') as usize;
+            let score = letter_freq[position][index];
+            best[index] = Some(best[index].map_or(score, |s| s.max(score)));
+        }
+    }
+    best.iter().flatten().sum()
+}
+
+/// All words of `dictionary` with their [word_score], best first.
+/// Ties keep the dictionary order.
+pub fn rank_all_words(dictionary: &dyn Dictionary) -> Vec<(&str, f64)> {
+    rank_words(dictionary)
+}
+
+/// The `n` best words of `dictionary` by [word_score], best first.
+///
+/// ```
+/// use wordler::dictionary::EnglishDictionary;
+/// use wordler::wordle::solver::top_n_words;
+///
+/// let dictionary = EnglishDictionary::from_slice(&["CRANE", "TRACE", "TRICE", "JUMPY"]);
+/// assert_eq!(top_n_words(&dictionary, 2), ["TRACE", "TRICE"]);
+/// ```
+pub fn top_n_words(dictionary: &dyn Dictionary, n: usize) -> Vec<&str> {
+    rank_all_words(dictionary)
+        .into_iter()
+        .take(n)
+        .map(|(word, _)| word)
+        .collect()
+}
+
+/// Size of the largest group of `answers` sharing the same outcome for `guess`.
+fn worst_case(guess: &str, answers: &[&str]) -> usize {
+    outcome_counts(guess, answers)
+        .into_iter()
+        .max()
+        .unwrap_or(0)
+}
+
+/// [TurnInput] of `word` with `matches`.
+pub(super) fn turn_input(word: &str, matches: [Match; 5]) -> TurnInput {
+    let mut turn_input = TurnInput::default();
+    for ((input, chr), mch) in turn_input.iter_mut().zip(word.bytes()).zip(matches) {
+        *input = Input { chr, mch };
+    }
+    turn_input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dictionary::EnglishDictionary;
+
+    const WORDS: [&str; 8] = [
+        "GREED", "GLIDE", "EDGER", "ELITE", "TRULY", "KELLY", "CRANE", "SLATE",
+    ];
+
+    #[test]
+    fn test_double_letters() {
+        // GREED played against GLIDE
+        let constraints = Constraints::from_guesses([&turn_input(
+            "GREED",
+            [
+                Match::ExactLocation,
+                Match::AbsentInWord,
+                Match::PresentInWord,
+                Match::AbsentInWord,
+                Match::PresentInWord,
+            ],
+        )]);
+
+        assert!(constraints.is_satisfied_by("GLIDE"));
+        assert!(constraints.is_satisfied_by("guide"));
+        // two E's
+        assert!(!constraints.is_satisfied_by("GEESE"));
+        // R is absent
+        assert!(!constraints.is_satisfied_by("GRIDE"));
+        // E can't be at position 2
+        assert!(!constraints.is_satisfied_by("GLEDS"));
+        assert!(!constraints.is_satisfied_by("GLID"));
+    }
+
+    #[test]
+    fn test_unplayed_rows() {
+        let constraints = Constraints::from_guesses(&[TurnInput::default()]);
+        assert_eq!(constraints, Constraints::default());
+
+        let mut guess = turn_input("GREED", [Match::AbsentInWord; 5]);
+        guess.iter_mut().next().unwrap().chr = b'#';
+        let constraints = Constraints::from_guesses([&guess]);
+        // only R, E and D are absent
+        assert!(constraints.is_satisfied_by("GLINT"));
+        assert!(!constraints.is_satisfied_by("GRINS"));
+    }
+
+    #[test]
+    fn test_display_and_debug() {
+        // GREED played against GLIDE
+        let constraints =
+            Constraints::from_guesses([&TurnInput::from_pattern("GREED", "G.Y.Y").unwrap()]);
+        let theme = ColorTheme::default();
+        let unknown = RGB(128, 128, 128).paint(" _ ");
+        let present = theme.style(&Match::PresentInWord).paint(" ? ");
+        assert_eq!(
+            constraints.to_string(),
+            format!(
+                "{}{}{}{}{}",
+                theme.style(&Match::ExactLocation).paint(" G "),
+                unknown,
+                present,
+                present,
+                present
+            )
+        );
+        assert_eq!(
+            format!("{:?}", constraints),
+            "Constraints { exact: [Some('G'), None, None, None, None], \
+             excluded: [\"\", \"R\", \"E\", \"E\", \"D\"], \
+             min_count: {'D': 1, 'E': 1, 'G': 1}, max_count: {'E': 1, 'R': 0} }"
+        );
+    }
+
+    #[test]
+    fn test_explain() {
+        // GREED played against GLIDE
+        let constraints =
+            Constraints::from_guesses([&TurnInput::from_pattern("GREED", "G.Y.Y").unwrap()]);
+        assert_eq!(
+            constraints.explain(),
+            "Position 1: must be 'G'\n\
+             Position 3: cannot be 'E'\n\
+             Position 4: cannot be 'E'\n\
+             Position 5: cannot be 'D'\n\
+             Must contain 'D'\n\
+             Must contain exactly 1 'E'\n\
+             Must contain 'G'\n\
+             Must not contain: R"
+        );
+
+        // KELLY played against TRULY
+        let constraints =
+            Constraints::from_guesses([&TurnInput::from_pattern("KELLY", "...GG").unwrap()]);
+        assert_eq!(
+            constraints.explain(),
+            "Position 3: cannot be 'L'\n\
+             Position 4: must be 'L'\n\
+             Position 5: must be 'Y'\n\
+             Must contain exactly 1 'L'\n\
+             Must contain 'Y'\n\
+             Must not contain: E, K"
+        );
+
+        // EDGER played against GREED
+        let constraints =
+            Constraints::from_guesses([&TurnInput::from_pattern("EDGER", "YYYGY").unwrap()]);
+        assert!(constraints
+            .explain()
+            .contains("Must contain at least 2 'E's"));
+        assert_eq!(Constraints::default().explain(), "");
+    }
+
+    #[test]
+    fn test_hard_mode_validator() {
+        // GREED played against GLIDE
+        let constraints =
+            Constraints::from_guesses([&TurnInput::from_pattern("GREED", "G.Y.Y").unwrap()]);
+        let validator = HardModeValidator;
+
+        assert!(validator.validate("glide", &constraints).is_ok());
+        assert!(validator.validate("GUIDE", &constraints).is_ok());
+        assert_eq!(
+            validator.validate("TRULY", &constraints),
+            Err(vec![
+                HardModeViolation::MustUseExactAt {
+                    letter: 'G',
+                    position: 0
+                },
+                HardModeViolation::MustInclude { letter: 'D' },
+                HardModeViolation::MustInclude { letter: 'E' },
+            ])
+        );
+        assert_eq!(
+            HardModeViolation::MustUseExactAt {
+                letter: 'G',
+                position: 0
+            }
+            .to_string(),
+            "You must use 'G' in position 1"
+        );
+    }
+
+    #[test]
+    fn test_solve_one() {
+        let dictionary = EnglishDictionary::from_slice(&WORDS);
+        for strategy in [&EntropyStrategy as &dyn SolveStrategy, &FrequencyStrategy] {
+            let stats = solve_one(&dictionary, "kelly", strategy).unwrap();
+            assert!(stats.won);
+            assert_eq!(stats.attempts_used as usize, stats.guesses.len());
+            assert_eq!(stats.guesses.last().unwrap(), "KELLY");
+        }
+        assert!(matches!(
+            solve_one(&dictionary, "JUMPY", &EntropyStrategy),
+            Err(WordleError::NotInDictionary(word, _)) if word == "JUMPY"
+        ));
+    }
+
+    #[test]
+    fn test_entropy_strategy() {
+        let remaining = ["JUMPY", "GREED", "GLIDE", "EDGER", "ELITE"];
+        assert_eq!(
+            EntropyStrategy.next_guess(&Constraints::default(), &remaining),
+            "GREED"
+        );
+        assert_eq!(EntropyStrategy.next_guess(&Constraints::default(), &[]), "");
+    }
+
+    #[test]
+    fn test_minimax_best_guess() {
+        // words differing by one letter are hard to tell apart
+        // by guessing them, but a word covering their first letters splits them
+        let ills = [
+            "BILLS", "FILLS", "HILLS", "KILLS", "MILLS", "PILLS", "TILLS", "WILLS",
+        ];
+        let mut words = ills.to_vec();
+        words.extend(["FIGHT", "KEMPT", "WHIMS"]);
+        let dictionary = EnglishDictionary::from_slice(&words);
+
+        let guess = minimax_best_guess(&ills, &dictionary);
+        assert_eq!(guess, "KEMPT");
+        assert!(worst_case(&guess, &ills) < worst_case("BILLS", &ills));
+
+        for answer in ills {
+            let mut constraints = Constraints::default();
+            let mut attempts = 0;
+            loop {
+                let remaining: Vec<&str> = ills
+                    .iter()
+                    .copied()
+                    .filter(|word| constraints.is_satisfied_by(word))
+                    .collect();
+                let guess = minimax_best_guess(&remaining, &dictionary);
+                let matches = evaluate_guess(&guess, answer).unwrap();
+                constraints.add(&turn_input(&guess, matches));
+                attempts += 1;
+                if guess == answer {
+                    break;
+                }
+            }
+            assert!(attempts <= 4, "{} solved in {} attempts", answer, attempts);
+        }
+
+        assert_eq!(minimax_best_guess(&[], &dictionary), "");
+    }
+
+    #[test]
+    fn test_solve_all() {
+        let dictionary = EnglishDictionary::from_slice(&WORDS);
+        let results = solve_all(&dictionary, &FrequencyStrategy);
+        assert_eq!(results.len(), WORDS.len());
+        assert!(results.iter().all(|(answer, stats)| stats.won
+            && stats.guesses.last() == Some(answer)
+            && stats.attempts_used <= 6));
+    }
+
+    #[test]
+    fn test_word_score() {
+        let mut frequency = [[0.0; 26]; 5];
+        frequency[0][(b'E' - b'A') as usize] = 0.5;
+        frequency[1][(b'E' - b'A') as usize] = 0.25;
+        frequency[4][(b'R' - b'A') as usize] = 1.0;
+        assert_eq!(word_score("EERIE", &frequency), 0.5);
+        assert_eq!(word_score("eager", &frequency), 1.5);
+        assert_eq!(word_score("", &frequency), 0.0);
+    }
+
+    #[test]
+    fn test_rank_all_words() {
+        let dictionary = EnglishDictionary::from_slice(&WORDS);
+        let ranked = rank_all_words(&dictionary);
+        assert_eq!(ranked.len(), WORDS.len());
+        assert!(ranked.windows(2).all(|w| w[0].1 >= w[1].1));
+        assert_eq!(
+            top_n_words(&dictionary, 3),
+            ranked
+                .iter()
+                .take(3)
+                .map(|(word, _)| *word)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(top_n_words(&dictionary, WORDS.len() + 1).len(), WORDS.len());
+    }
+}
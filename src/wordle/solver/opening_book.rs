@@ -0,0 +1,215 @@
+//! Best second guesses after [OPENING_GUESS], pre-computed with
+//! [EntropyStrategy](super::EntropyStrategy) over the common words bundled with the crate,
+//! see [EnglishDictionary::with_common_only](crate::dictionary::EnglishDictionary::with_common_only).
+//!
+//! Looking them up is instant while [EntropyStrategy](super::EntropyStrategy) takes seconds
+//! for the first moves on a large dictionary, but other dictionaries may have better guesses.
+//! [OpeningBookStrategy] plays them before handing over to another strategy.
+//!
+use super::{turn_input, Constraints, SolveStrategy};
+use crate::wordle::eval::{evaluate_guess, pattern_to_index, PATTERN_COUNT};
+use crate::wordle::Match;
+
+/// The first guess the book is built for, the word of the bundled common words
+/// with the highest entropy of outcomes.
+pub const OPENING_GUESS: &str = "TARES";
+
+/// Best second guess for each outcome of [OPENING_GUESS], indexed by
+/// [pattern_to_index], empty when no common word has that outcome.
+pub const OPENING_BOOK: [&str; PATTERN_COUNT] = [
+    "COULD", "SPOIL", "LIONS", "GENIE", "SPINE", "SLEDS", "LODEN", "SPIED", "DOLES", "PRION",
+    "SCOUR", "DROPS", "PRIDE", "PROSE", "WEIRS", "RILED", "SHIER", "ROUES", "CURLY", "SCRUB",
+    "CORDS", "NERDY", "NURSE", "KERBS", "CORER", "SIREE", "MORES", "LIANA", "SCALP", "COALS",
+    "PLANE", "LEASH", "LEADS", "ALIEN", "ASKED", "AIDES", "DRAIN", "SCARP", "CRAGS", "BEARD",
+    "SHARE", "PEARS", "ALDER", "ASKER", "BRAES", "NORIA", "SCRAP", "ARRAS", "FERIA", "VERSA", "",
+    "AGREE", "", "ACRES", "MANLY", "SALSA", "MAILS", "GABLE", "PAUSE", "", "LADEN", "EASEL",
+    "MALES", "RADON", "SABRA", "PAIRS", "CADRE", "RAISE", "", "CAGER", "SAVER", "RACES", "CAROL",
+    "MARSH", "MARCS", "CARVE", "PARSE", "EARNS", "CARED", "", "FARES", "LITHO", "HOIST", "SILTS",
+    "ELITE", "SPITE", "WESTS", "COLET", "STEEL", "MITES", "FRUIT", "SHIRT", "RIOTS", "OUTRE",
+    "CREST", "RESTS", "OUTER", "STEER", "RITES", "WORTH", "WURST", "PORTS", "HERTZ", "", "CERTS",
+    "EGRET", "STREP", "", "ACTIN", "SLANT", "SLATS", "LEAPT", "STALE", "BEATS", "ACTED", "ASSET",
+    "ANTES", "ARGOT", "STAIR", "BRATS", "PEART", "STARE", "", "AFTER", "ASTER", "", "AORTA",
+    "STRAP", "", "", "", "", "", "", "", "MATIN", "SAITH", "CASTS", "LATHE", "WASTE", "EASTS",
+    "LATED", "SATED", "DATES", "RATIO", "SATYR", "RANTS", "RATHE", "", "", "RATED", "", "RATES",
+    "PARTY", "KARST", "PARTS", "EARTH", "", "", "CARET", "", "", "TILTH", "TWIST", "TINTS",
+    "THINE", "THESE", "TENTS", "TONED", "", "TONES", "TRULY", "TRUST", "TRIOS", "TRIPE", "",
+    "TREKS", "TUNER", "", "TREES", "TORCH", "TORSO", "TORTS", "TORTE", "TERSE", "TERMS", "THREE",
+    "", "TIRES", "TIDAL", "TOAST", "TOADS", "TEACH", "TESLA", "TEAMS", "TINEA", "", "", "TRAIL",
+    "TRASH", "TRANS", "TRADE", "", "TEARS", "", "", "", "", "", "", "TERRA", "", "", "", "", "",
+    "TANKA", "TASTY", "TALKS", "TABLE", "TASTE", "", "TAXED", "", "TAKES", "TAPIR", "", "", "", "",
+    "", "TAPER", "", "", "TARDY", "TARSI", "TARTS", "TARGE", "", "", "", "", "TARES",
+];
+
+/// The recommended second guess after [OPENING_GUESS] had `first_result`, empty
+/// when no bundled common word has that outcome.
+///
+/// ```
+/// use wordler::wordle::eval::evaluate_guess;
+/// use wordler::wordle::solver::opening_book::{opening_book_guess, OPENING_GUESS};
+///
+/// let first_result = evaluate_guess(OPENING_GUESS, "STARE").unwrap();
+/// assert_eq!(opening_book_guess(&first_result), "STARE");
+/// ```
+pub fn opening_book_guess(first_result: &[Match; 5]) -> &'static str {
+    OPENING_BOOK[pattern_to_index(first_result) as usize]
+}
+
+/// Plays [OPENING_GUESS] and then the [opening_book_guess] for its outcome,
+/// and the guesses of `inner` after that.
+///
+/// `inner` also picks the guess when the book's one is not among the remaining words,
+/// like on a dictionary without the bundled common words.
+///
+/// ```
+/// use wordler::dictionary::EnglishDictionary;
+/// use wordler::wordle::solver::opening_book::OpeningBookStrategy;
+/// use wordler::wordle::solver::{solve_one, EntropyStrategy};
+///
+/// let dictionary = EnglishDictionary::from_slice(&["TARES", "STARE", "CRANE"]);
+/// let strategy = OpeningBookStrategy::new(EntropyStrategy);
+/// let stats = solve_one(&dictionary, "STARE", &strategy).unwrap();
+/// assert_eq!(stats.guesses, ["TARES", "STARE"]);
+/// ```
+#[derive(Debug, Default, Clone, Copy)]
+pub struct OpeningBookStrategy<S: SolveStrategy> {
+    inner: S,
+}
+
+impl<S: SolveStrategy> OpeningBookStrategy<S> {
+    /// Create an OpeningBookStrategy handing over to `inner` after the book.
+    pub fn new(inner: S) -> Self {
+        OpeningBookStrategy { inner }
+    }
+
+    /// The strategy playing after the book.
+    pub fn inner(&self) -> &S {
+        &self.inner
+    }
+}
+
+impl<S: SolveStrategy> SolveStrategy for OpeningBookStrategy<S> {
+    fn next_guess(&self, constraints: &Constraints, remaining: &[&str]) -> String {
+        match book_guess(constraints, remaining) {
+            Some(guess) if remaining.contains(&guess) => guess.to_string(),
+            _ => self.inner.next_guess(constraints, remaining),
+        }
+    }
+}
+
+/// The book's guess when nothing was played yet or only [OPENING_GUESS] was.
+fn book_guess(constraints: &Constraints, remaining: &[&str]) -> Option<&'static str> {
+    if *constraints == Constraints::default() {
+        return Some(OPENING_GUESS);
+    }
+    // after the opening guess all remaining words share its outcome
+    let first_result = evaluate_guess(OPENING_GUESS, remaining.first()?).ok()?;
+    let after_opening = Constraints::from_guesses([&turn_input(OPENING_GUESS, first_result)]);
+    (*constraints == after_opening).then(|| opening_book_guess(&first_result))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dictionary::{Dictionary, EnglishDictionary};
+    use crate::wordle::eval::index_to_pattern;
+    use crate::wordle::solver::{solve_one, EntropyStrategy, FrequencyStrategy};
+
+    fn common_words() -> Vec<&'static str> {
+        include_str!("../../../assets/common_words.txt")
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .collect()
+    }
+
+    /// Common words with each outcome of [OPENING_GUESS].
+    fn buckets<'a>(words: &[&'a str]) -> Vec<Vec<&'a str>> {
+        let mut buckets = vec![Vec::new(); PATTERN_COUNT];
+        for word in words {
+            let pattern = evaluate_guess(OPENING_GUESS, word).unwrap();
+            buckets[pattern_to_index(&pattern) as usize].push(*word);
+        }
+        buckets
+    }
+
+    #[test]
+    fn test_opening_book_matches_common_words() {
+        let dictionary = EnglishDictionary::from_slice(&common_words());
+        let words = dictionary.words();
+        assert!(words.contains(&OPENING_GUESS));
+
+        let buckets = buckets(&words);
+        for (index, (expected, bucket)) in OPENING_BOOK.iter().zip(&buckets).enumerate() {
+            if bucket.is_empty() {
+                assert_eq!(*expected, "", "outcome {}", index);
+            } else {
+                assert!(bucket.contains(expected), "outcome {}", index);
+            }
+        }
+
+        // recomputing the small buckets is cheap
+        for (index, bucket) in buckets.iter().enumerate() {
+            if bucket.is_empty() || bucket.len() > 20 {
+                continue;
+            }
+            let pattern = index_to_pattern(index as u8);
+            let constraints = Constraints::from_guesses([&turn_input(OPENING_GUESS, pattern)]);
+            assert_eq!(
+                EntropyStrategy.next_guess(&constraints, bucket),
+                OPENING_BOOK[index],
+                "outcome {}",
+                index
+            );
+        }
+    }
+
+    /// Recomputes the whole book, which takes a minute in debug builds.
+    #[test]
+    #[ignore]
+    fn test_opening_book_is_up_to_date() {
+        let dictionary = EnglishDictionary::from_slice(&common_words());
+        let words = dictionary.words();
+        assert_eq!(
+            EntropyStrategy.next_guess(&Constraints::default(), &words),
+            OPENING_GUESS
+        );
+
+        for (index, expected) in OPENING_BOOK.iter().enumerate() {
+            let pattern = index_to_pattern(index as u8);
+            let remaining: Vec<&str> = words
+                .iter()
+                .copied()
+                .filter(|word| evaluate_guess(OPENING_GUESS, word).unwrap() == pattern)
+                .collect();
+            let constraints = Constraints::from_guesses([&turn_input(OPENING_GUESS, pattern)]);
+            assert_eq!(
+                EntropyStrategy.next_guess(&constraints, &remaining),
+                *expected,
+                "outcome {}",
+                index
+            );
+        }
+    }
+
+    #[test]
+    fn test_opening_book_strategy() {
+        let strategy = OpeningBookStrategy::new(FrequencyStrategy);
+        assert_eq!(
+            strategy.next_guess(&Constraints::default(), &["CRANE", "TARES"]),
+            OPENING_GUESS
+        );
+        // without the opening guess in the dictionary the inner strategy plays
+        assert_eq!(
+            strategy.next_guess(&Constraints::default(), &["CRANE"]),
+            "CRANE"
+        );
+
+        let dictionary = EnglishDictionary::from_slice(&common_words());
+        let answer = "WORLD";
+        let first_result = evaluate_guess(OPENING_GUESS, answer).unwrap();
+        let stats = solve_one(&dictionary, answer, &strategy).unwrap();
+        assert!(stats.won);
+        assert_eq!(stats.guesses[0], OPENING_GUESS);
+        assert_eq!(stats.guesses[1], opening_book_guess(&first_result));
+    }
+}
@@ -0,0 +1,140 @@
+//! Statistics over the words of a [Dictionary] to pick good guesses.
+//!
+use super::solver::{solve_one, word_score, SolveStats, SolveStrategy};
+use super::WordleError;
+use crate::dictionary::{positional_frequency, Dictionary};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// The word of `dictionary` covering the most common letters at their position,
+/// see [rank_words]. Empty if `dictionary` has no words.
+///
+/// ```
+/// use wordler::dictionary::EnglishDictionary;
+/// use wordler::wordle::stats::best_first_guess;
+///
+/// let dictionary = EnglishDictionary::from_slice(&["CRANE", "TRACE", "TRICE", "JUMPY"]);
+/// assert_eq!(best_first_guess(&dictionary), "TRACE");
+/// ```
+pub fn best_first_guess(dictionary: &dyn Dictionary) -> String {
+    rank_words(dictionary)
+        .first()
+        .map(|(word, _)| word.to_string())
+        .unwrap_or_default()
+}
+
+/// All words of `dictionary` with their letter frequency score, best first.
+///
+/// The score of a word is the sum of the fraction of words having each of its
+/// letters at the same position. A repeated letter only counts once, at the
+/// position where it's most common, as it reveals nothing new the second time.
+/// Ties keep the dictionary order.
+pub fn rank_words(dictionary: &dyn Dictionary) -> Vec<(&str, f64)> {
+    rank_by_coverage(dictionary.words())
+}
+
+/// Score `words` among themselves, see [rank_words].
+pub(crate) fn rank_by_coverage(words: Vec<&str>) -> Vec<(&str, f64)> {
+    let frequency = positional_frequency(words.iter().copied());
+    let mut ranked: Vec<(&str, f64)> = words
+        .into_iter()
+        .map(|word| (word, word_score(word, &frequency)))
+        .collect();
+    ranked.sort_by(|(_, a), (_, b)| b.total_cmp(a));
+    ranked
+}
+
+/// Number of words of `dictionary` solved by `strategy` in 1 to 6 attempts,
+/// and at index 6 the number not solved, see [solve_one].
+///
+/// Words the strategy fails to play, by guessing an invalid word, count as not solved.
+///
+/// ```
+/// use wordler::dictionary::EnglishDictionary;
+/// use wordler::wordle::solver::FrequencyStrategy;
+/// use wordler::wordle::stats::solve_distribution;
+///
+/// let dictionary = EnglishDictionary::from_slice(&["CRANE", "TRACE", "TRICE", "JUMPY"]);
+/// let distribution = solve_distribution(&dictionary, &FrequencyStrategy);
+/// assert_eq!(distribution.iter().sum::<u32>(), 4);
+/// ```
+pub fn solve_distribution(dictionary: &dyn Dictionary, strategy: &dyn SolveStrategy) -> [u32; 7] {
+    tally(
+        dictionary
+            .words()
+            .into_iter()
+            .map(|word| solve_one(dictionary, word, strategy)),
+    )
+}
+
+/// [solve_distribution] solving words in parallel.
+#[cfg(feature = "parallel")]
+pub fn solve_distribution_par(
+    dictionary: &(dyn Dictionary + Sync),
+    strategy: &(dyn SolveStrategy + Sync),
+) -> [u32; 7] {
+    let results: Vec<Result<SolveStats, WordleError>> = dictionary
+        .words()
+        .into_par_iter()
+        .map(|word| solve_one(dictionary, word, strategy))
+        .collect();
+    tally(results)
+}
+
+fn tally(results: impl IntoIterator<Item = Result<SolveStats, WordleError>>) -> [u32; 7] {
+    let mut distribution = [0; 7];
+    for result in results {
+        match result {
+            Ok(stats) if stats.won => distribution[stats.attempts_used as usize - 1] += 1,
+            _ => distribution[6] += 1,
+        }
+    }
+    distribution
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dictionary::EnglishDictionary;
+
+    #[test]
+    fn test_rank_words() {
+        let dictionary = EnglishDictionary::from_slice(&["GREED", "GLIDE", "EDGER", "GEESE"]);
+        let ranked = rank_words(&dictionary);
+        assert_eq!(ranked.len(), 4);
+        assert!(ranked.windows(2).all(|w| w[0].1 >= w[1].1));
+        // G at 0 in 3 of 4 words, S at 3 in 1, E counted once at its best position
+        let geese = ranked.iter().find(|(word, _)| *word == "GEESE").unwrap();
+        assert_eq!(geese.1, 0.75 + 0.5 + 0.25);
+        assert_eq!(ranked[0].0, "GLIDE");
+        assert_eq!(best_first_guess(&dictionary), "GLIDE");
+    }
+
+    #[test]
+    fn test_solve_distribution() {
+        use crate::wordle::solver::{EntropyStrategy, FrequencyStrategy};
+
+        let dictionary = EnglishDictionary::from_slice(&["CRANE", "TRACE", "TRICE", "JUMPY"]);
+        // TRACE first, then the only word left
+        assert_eq!(
+            solve_distribution(&dictionary, &FrequencyStrategy),
+            [1, 3, 0, 0, 0, 0, 0]
+        );
+        let entropy = solve_distribution(&dictionary, &EntropyStrategy);
+        assert_eq!(entropy.iter().sum::<u32>(), 4);
+        assert_eq!(entropy[6], 0);
+
+        #[cfg(feature = "parallel")]
+        assert_eq!(
+            solve_distribution_par(&dictionary, &FrequencyStrategy),
+            [1, 3, 0, 0, 0, 0, 0]
+        );
+    }
+
+    #[test]
+    fn test_best_first_guess_empty() {
+        let dictionary = EnglishDictionary::from_slice(&[]);
+        assert!(rank_words(&dictionary).is_empty());
+        assert_eq!(best_first_guess(&dictionary), "");
+    }
+}
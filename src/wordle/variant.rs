@@ -0,0 +1,567 @@
+//! A [WordleGame] trait to play [Wordle] and its variants [Absurdle] and [Dordle]
+//! through the same interface.
+//!
+use super::eval::{evaluate_guess, is_solved, matches_to_score, pattern_to_index, PATTERN_COUNT};
+use super::session::GameResult;
+use super::{
+    print_keyboard_state_themed, validate_guess, ColorTheme, Match, PlayResult, Wordle, WordleError,
+};
+use crate::dictionary::{Dictionary, DictionaryError};
+use crossterm::cursor::MoveTo;
+use crossterm::terminal::{Clear, ClearType};
+use std::collections::HashMap;
+use std::io::{BufRead, Write};
+
+/// Attempts to solve both words of a [Dordle].
+const DORDLE_MAX_ATTEMPTS: u8 = 7;
+
+/// State of a [WordleGame].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GameState {
+    /// Attempts remain and the game has not been won.
+    InProgress,
+    /// All answers have been guessed.
+    Won,
+    /// All attempts were used without guessing all answers.
+    Lost,
+}
+
+/// Output of a single play of a [WordleGame], like [PlayResult] for any word length
+/// and number of boards.
+///
+/// Each row is the letters played with their [Match], one row for each board
+/// not solved before this play, in order of the boards.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[must_use = "the game outcome contains match information that should be processed"]
+pub enum GameOutcome {
+    /// When the game has not ended.
+    TurnResult {
+        /// Attempt number of this play.
+        attempt: u8,
+        /// Match outcome of this play on each board.
+        rows: Vec<Vec<(char, Match)>>,
+    },
+    /// When all answers have been guessed.
+    YouWon {
+        /// Attempt number of this play.
+        attempt: u8,
+        /// Match outcome of this play on each board.
+        rows: Vec<Vec<(char, Match)>>,
+    },
+    /// When all attempts were used, revealing the answers.
+    YouLost {
+        /// Attempt number of this play.
+        attempt: u8,
+        /// Match outcome of this play on each board.
+        rows: Vec<Vec<(char, Match)>>,
+        /// The actual answers.
+        answers: Vec<String>,
+    },
+}
+
+impl GameOutcome {
+    /// Attempt number of this play.
+    pub fn attempt(&self) -> u8 {
+        match self {
+            GameOutcome::TurnResult { attempt, .. }
+            | GameOutcome::YouWon { attempt, .. }
+            | GameOutcome::YouLost { attempt, .. } => *attempt,
+        }
+    }
+
+    /// Match outcome of this play on each board not solved before it.
+    pub fn rows(&self) -> &[Vec<(char, Match)>] {
+        match self {
+            GameOutcome::TurnResult { rows, .. }
+            | GameOutcome::YouWon { rows, .. }
+            | GameOutcome::YouLost { rows, .. } => rows,
+        }
+    }
+
+    /// Check if this play ended the game, won or lost.
+    pub fn is_terminal(&self) -> bool {
+        !matches!(self, GameOutcome::TurnResult { .. })
+    }
+
+    /// Check if this play won the game.
+    pub fn is_won(&self) -> bool {
+        matches!(self, GameOutcome::YouWon { .. })
+    }
+}
+
+impl From<PlayResult<'_>> for GameOutcome {
+    fn from(play_result: PlayResult<'_>) -> Self {
+        let attempt = play_result.attempt();
+        let rows = vec![super::owned_row(play_result.turn_input())];
+        match play_result {
+            PlayResult::TurnResult { .. } => GameOutcome::TurnResult { attempt, rows },
+            PlayResult::YouWon { .. } => GameOutcome::YouWon { attempt, rows },
+            PlayResult::YouLost { answer, .. } => GameOutcome::YouLost {
+                attempt,
+                rows,
+                answers: vec![answer.to_string()],
+            },
+        }
+    }
+}
+
+/// A game where guesses are played until the answers are found or attempts run out.
+pub trait WordleGame {
+    /// Take user input as `word` and return the play outcome.
+    fn play(&mut self, word: &str) -> Result<GameOutcome, WordleError>;
+    /// Whether the game is in progress, won or lost.
+    fn state(&self) -> GameState;
+    /// Number of letters of the answers and guesses.
+    fn word_length(&self) -> usize;
+    /// Number of attempts to find the answers.
+    fn max_attempts(&self) -> u8;
+    /// Number of guesses played so far.
+    fn attempts_made(&self) -> u8;
+}
+
+impl WordleGame for Wordle<'_> {
+    fn play(&mut self, word: &str) -> Result<GameOutcome, WordleError> {
+        Ok(Wordle::play(self, word)?.into())
+    }
+
+    fn state(&self) -> GameState {
+        if self.is_won() {
+            GameState::Won
+        } else if self.is_ended() {
+            GameState::Lost
+        } else {
+            GameState::InProgress
+        }
+    }
+
+    fn word_length(&self) -> usize {
+        5
+    }
+
+    fn max_attempts(&self) -> u8 {
+        6
+    }
+
+    fn attempts_made(&self) -> u8 {
+        Wordle::attempts_made(self)
+    }
+}
+
+/// Adversarial Wordle: the answer is not chosen upfront, instead each guess gets
+/// the outcome keeping the most words possible, until the guess is the only one left.
+///
+/// ```
+/// use wordler::dictionary::EnglishDictionary;
+/// use wordler::wordle::variant::{Absurdle, GameState, WordleGame};
+///
+/// let dictionary = EnglishDictionary::from_slice(&["CRANE", "CRATE", "JUMPY"]);
+/// let mut absurdle = Absurdle::new(&dictionary).unwrap();
+/// // JUMPY is avoided as long as CRANE or CRATE remain
+/// assert!(!absurdle.play("JUMPY").unwrap().is_terminal());
+/// assert_eq!(absurdle.remaining_words(), ["CRANE", "CRATE"]);
+/// ```
+pub struct Absurdle<'w> {
+    dictionary: &'w dyn Dictionary,
+    remaining: Vec<&'w str>,
+    attempts_made: u8,
+    won: bool,
+}
+
+impl<'w> Absurdle<'w> {
+    /// Create a new Absurdle game with all words of `dictionary` as possible answers.
+    ///
+    /// Fails when `dictionary` cannot list any word.
+    pub fn new(dictionary: &'w dyn Dictionary) -> Result<Self, WordleError> {
+        let remaining = dictionary.words();
+        if remaining.is_empty() {
+            return Err(DictionaryError::EmptyWordList.into());
+        }
+        Ok(Absurdle {
+            dictionary,
+            remaining,
+            attempts_made: 0,
+            won: false,
+        })
+    }
+
+    /// Words which can still be the answer given the outcome of all plays so far.
+    pub fn remaining_words(&self) -> &[&'w str] {
+        &self.remaining
+    }
+}
+
+impl WordleGame for Absurdle<'_> {
+    /// Play `word` against the remaining words, keeping the largest group of words
+    /// giving the same outcome. Ties go to the outcome revealing the least.
+    fn play(&mut self, word: &str) -> Result<GameOutcome, WordleError> {
+        if self.state() != GameState::InProgress {
+            return Err(WordleError::GameEnded);
+        }
+        let word = validate_guess(self.dictionary, word)?;
+
+        let mut groups: Vec<Vec<&str>> = vec![Vec::new(); PATTERN_COUNT];
+        for answer in &self.remaining {
+            let matches = evaluate_guess(&word, answer)?;
+            groups[pattern_to_index(&matches) as usize].push(answer);
+        }
+        let (matches, group) = groups
+            .into_iter()
+            .enumerate()
+            .filter(|(_, group)| !group.is_empty())
+            .map(|(index, group)| (super::eval::index_to_pattern(index as u8), group))
+            .max_by(|(a, group_a), (b, group_b)| {
+                group_a
+                    .len()
+                    .cmp(&group_b.len())
+                    .then(matches_to_score(b).cmp(&matches_to_score(a)))
+            })
+            .expect("remaining words are never empty");
+        self.remaining = group;
+        self.attempts_made += 1;
+        self.won = is_solved(&matches);
+
+        let attempt = self.attempts_made;
+        let rows = vec![word.chars().zip(matches).collect()];
+        Ok(match self.state() {
+            GameState::Won => GameOutcome::YouWon { attempt, rows },
+            GameState::Lost => GameOutcome::YouLost {
+                attempt,
+                rows,
+                answers: self.remaining.iter().map(|w| w.to_string()).collect(),
+            },
+            GameState::InProgress => GameOutcome::TurnResult { attempt, rows },
+        })
+    }
+
+    fn state(&self) -> GameState {
+        if self.won {
+            GameState::Won
+        } else if self.attempts_made == self.max_attempts() {
+            GameState::Lost
+        } else {
+            GameState::InProgress
+        }
+    }
+
+    fn word_length(&self) -> usize {
+        5
+    }
+
+    /// As many attempts as needed in practice, 255.
+    fn max_attempts(&self) -> u8 {
+        u8::MAX
+    }
+
+    fn attempts_made(&self) -> u8 {
+        self.attempts_made
+    }
+}
+
+/// Two Wordle games played with the same guesses, to be both solved in 7 attempts.
+///
+/// ```
+/// use wordler::dictionary::EnglishDictionary;
+/// use wordler::wordle::variant::{Dordle, GameState, WordleGame};
+///
+/// let dictionary = EnglishDictionary::from_slice(&["CRANE", "SLATE"]);
+/// let mut dordle = Dordle::new_with_words(&dictionary, "crane", "slate").unwrap();
+/// assert_eq!(dordle.play("SLATE").unwrap().rows().len(), 2);
+/// assert_eq!(dordle.play("CRANE").unwrap().rows().len(), 1);
+/// assert_eq!(dordle.state(), GameState::Won);
+/// ```
+pub struct Dordle<'w> {
+    dictionary: &'w dyn Dictionary,
+    answers: [String; 2],
+    solved: [bool; 2],
+    attempts_made: u8,
+}
+
+impl<'w> Dordle<'w> {
+    /// Create a new Dordle game with two different random words of `dictionary`,
+    /// when it has more than one.
+    pub fn new(dictionary: &'w dyn Dictionary) -> Self {
+        let words = dictionary.random_n_words(2);
+        let first = words
+            .first()
+            .copied()
+            .unwrap_or_else(|| dictionary.random_word());
+        let second = words.last().copied().unwrap_or(first);
+        Self::with_answers(dictionary, [first.to_string(), second.to_string()])
+    }
+
+    /// Create a new Dordle game with the given answers, in any case.
+    ///
+    /// Fails with [WordleError::NotInDictionary] when either is not in `dictionary`.
+    pub fn new_with_words(
+        dictionary: &'w dyn Dictionary,
+        first: &str,
+        second: &str,
+    ) -> Result<Self, WordleError> {
+        let mut answers = [first.to_uppercase(), second.to_uppercase()];
+        for answer in &mut answers {
+            if !dictionary.is_valid_word(answer) {
+                return Err(WordleError::NotInDictionary(
+                    std::mem::take(answer),
+                    Vec::new(),
+                ));
+            }
+        }
+        Ok(Self::with_answers(dictionary, answers))
+    }
+
+    fn with_answers(dictionary: &'w dyn Dictionary, answers: [String; 2]) -> Self {
+        Dordle {
+            dictionary,
+            answers,
+            solved: [false; 2],
+            attempts_made: 0,
+        }
+    }
+}
+
+impl WordleGame for Dordle<'_> {
+    fn play(&mut self, word: &str) -> Result<GameOutcome, WordleError> {
+        if self.state() != GameState::InProgress {
+            return Err(WordleError::GameEnded);
+        }
+        let word = validate_guess(self.dictionary, word)?;
+
+        let mut rows = Vec::new();
+        for (answer, solved) in self.answers.iter().zip(self.solved.iter_mut()) {
+            if *solved {
+                continue;
+            }
+            let matches = evaluate_guess(&word, answer)?;
+            *solved = is_solved(&matches);
+            rows.push(word.chars().zip(matches).collect());
+        }
+        self.attempts_made += 1;
+
+        let attempt = self.attempts_made;
+        Ok(match self.state() {
+            GameState::Won => GameOutcome::YouWon { attempt, rows },
+            GameState::Lost => GameOutcome::YouLost {
+                attempt,
+                rows,
+                answers: self.answers.to_vec(),
+            },
+            GameState::InProgress => GameOutcome::TurnResult { attempt, rows },
+        })
+    }
+
+    fn state(&self) -> GameState {
+        if self.solved.iter().all(|solved| *solved) {
+            GameState::Won
+        } else if self.attempts_made == self.max_attempts() {
+            GameState::Lost
+        } else {
+            GameState::InProgress
+        }
+    }
+
+    fn word_length(&self) -> usize {
+        5
+    }
+
+    fn max_attempts(&self) -> u8 {
+        DORDLE_MAX_ATTEMPTS
+    }
+
+    fn attempts_made(&self) -> u8 {
+        self.attempts_made
+    }
+}
+
+/// Play `game` until it ends, reading guesses line by line from `stdin`
+/// and redrawing the outcomes and keyboard on `stdout` after each one,
+/// like [play_interactive](super::play_interactive) for any [WordleGame].
+///
+/// Fails with [WordleError::Io] if `stdin` ends before the game does.
+pub fn play_game_interactive(
+    game: &mut dyn WordleGame,
+    stdin: &mut impl BufRead,
+    stdout: &mut impl Write,
+    theme: ColorTheme,
+) -> Result<GameResult, WordleError> {
+    let mut outcomes: Vec<GameOutcome> = Vec::new();
+    let mut current_guess = String::new();
+    let mut message = None;
+    loop {
+        redraw_outcomes(&outcomes, stdout, theme)?;
+        if let Some(message) = message.take() {
+            writeln!(stdout, "{}", message)?;
+        }
+        print_keyboard_state_themed(&keyboard_state(&outcomes), stdout, theme)?;
+
+        current_guess.clear();
+        write!(stdout, "Enter your guess [{}", game.attempts_made() + 1)?;
+        // no limit worth showing, like for Absurdle
+        if game.max_attempts() < u8::MAX {
+            write!(stdout, "/{}", game.max_attempts())?;
+        }
+        writeln!(stdout, "]")?;
+        if stdin.read_line(&mut current_guess)? == 0 {
+            return Err(WordleError::Io(std::io::ErrorKind::UnexpectedEof.into()));
+        }
+        match game.play(current_guess.trim()) {
+            Ok(outcome) => outcomes.push(outcome),
+            Err(e) => message = Some(e.to_string()),
+        }
+
+        if let Some(outcome) = outcomes.last().filter(|outcome| outcome.is_terminal()) {
+            redraw_outcomes(&outcomes, stdout, theme)?;
+            match outcome {
+                GameOutcome::YouLost { answers, .. } => {
+                    write!(stdout, "{}", super::lost_message(&answers.join(", ")))?
+                }
+                _ => write!(stdout, "{}", super::WON_MESSAGE)?,
+            }
+            return Ok(GameResult {
+                won: outcome.is_won(),
+                attempts_used: outcome.attempt(),
+                time: None,
+                game_id: None,
+            });
+        }
+    }
+}
+
+/// Best known [Match] of each letter played on any board.
+fn keyboard_state(outcomes: &[GameOutcome]) -> HashMap<char, Match> {
+    let mut state = HashMap::new();
+    for (letter, mch) in outcomes.iter().flat_map(GameOutcome::rows).flatten() {
+        let entry = state.entry(*letter).or_insert(*mch);
+        *entry = std::cmp::max(*entry, *mch);
+    }
+    state
+}
+
+/// Clear the terminal and write each outcome on a line, boards side by side.
+fn redraw_outcomes(
+    outcomes: &[GameOutcome],
+    stdout: &mut impl Write,
+    theme: ColorTheme,
+) -> std::io::Result<()> {
+    crossterm::queue!(stdout, Clear(ClearType::All), MoveTo(0, 0))?;
+    for outcome in outcomes {
+        for (board, row) in outcome.rows().iter().enumerate() {
+            if board > 0 {
+                write!(stdout, "   ")?;
+            }
+            for (letter, mch) in row {
+                write!(
+                    stdout,
+                    "{}",
+                    theme.style(mch).paint(format!(" {} ", letter))
+                )?;
+            }
+        }
+        writeln!(stdout)?;
+    }
+    writeln!(stdout)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dictionary::EnglishDictionary;
+
+    #[test]
+    fn test_wordle_game() {
+        let dictionary = EnglishDictionary::from_slice(&["ARIEL", "DREAM"]);
+        let mut wordle = Wordle::new_with_word(&dictionary, "ARIEL").unwrap();
+        let game: &mut dyn WordleGame = &mut wordle;
+        let outcome = game.play("dream").unwrap();
+        assert_eq!(
+            outcome,
+            GameOutcome::TurnResult {
+                attempt: 1,
+                rows: vec![vec![
+                    ('D', Match::AbsentInWord),
+                    ('R', Match::ExactLocation),
+                    ('E', Match::PresentInWord),
+                    ('A', Match::PresentInWord),
+                    ('M', Match::AbsentInWord),
+                ]]
+            }
+        );
+        assert_eq!(game.state(), GameState::InProgress);
+        assert!(game.play("ARIEL").unwrap().is_won());
+        assert_eq!(game.state(), GameState::Won);
+        assert_eq!((game.attempts_made(), game.max_attempts()), (2, 6));
+    }
+
+    #[test]
+    fn test_absurdle() {
+        let dictionary = EnglishDictionary::from_slice(&["CRANE", "CRATE", "JUMPY"]);
+        let mut absurdle = Absurdle::new(&dictionary).unwrap();
+        assert!(!absurdle.play("JUMPY").unwrap().is_terminal());
+        assert!(absurdle.play("XXXXX").is_err());
+
+        // CRANE and CRATE each leave one word, the tie goes to the outcome revealing less
+        let outcome = absurdle.play("CRANE").unwrap();
+        assert_eq!(
+            outcome.rows()[0]
+                .iter()
+                .map(|(_, m)| *m)
+                .collect::<Vec<_>>(),
+            [
+                Match::ExactLocation,
+                Match::ExactLocation,
+                Match::ExactLocation,
+                Match::AbsentInWord,
+                Match::ExactLocation,
+            ]
+        );
+        assert_eq!(absurdle.remaining_words(), ["CRATE"]);
+
+        let outcome = absurdle.play("CRATE").unwrap();
+        assert!(outcome.is_won());
+        assert_eq!(outcome.attempt(), 3);
+        assert!(matches!(
+            absurdle.play("CRATE"),
+            Err(WordleError::GameEnded)
+        ));
+
+        let empty = EnglishDictionary::from_slice(&[]);
+        assert!(Absurdle::new(&empty).is_err());
+    }
+
+    #[test]
+    fn test_dordle() {
+        let dictionary = EnglishDictionary::from_slice(&["CRANE", "SLATE", "JUMPY"]);
+        let mut dordle = Dordle::new_with_words(&dictionary, "CRANE", "SLATE").unwrap();
+        for _ in 0..6 {
+            assert_eq!(dordle.play("JUMPY").unwrap().rows().len(), 2);
+        }
+        assert_eq!(dordle.play("CRANE").unwrap().rows().len(), 2);
+        assert_eq!(dordle.state(), GameState::Lost);
+        assert!(matches!(dordle.play("SLATE"), Err(WordleError::GameEnded)));
+
+        assert!(matches!(
+            Dordle::new_with_words(&dictionary, "CRANE", "XXXXX"),
+            Err(WordleError::NotInDictionary(word, _)) if word == "XXXXX"
+        ));
+
+        let dordle = Dordle::new(&dictionary);
+        assert_ne!(dordle.answers[0], dordle.answers[1]);
+    }
+
+    #[test]
+    fn test_play_game_interactive() {
+        let dictionary = EnglishDictionary::from_slice(&["CRANE", "SLATE", "JUMPY"]);
+        let mut dordle = Dordle::new_with_words(&dictionary, "CRANE", "SLATE").unwrap();
+        let mut stdin = std::io::Cursor::new("slate\nxxxxx\ncrane\n".as_bytes());
+        let mut stdout = Vec::new();
+        let result =
+            play_game_interactive(&mut dordle, &mut stdin, &mut stdout, ColorTheme::Monochrome)
+                .unwrap();
+        assert!(result.won);
+        assert_eq!(result.attempts_used, 2);
+
+        let stdout = String::from_utf8(stdout).unwrap();
+        assert!(stdout.contains("Enter your guess [1/7]"));
+        assert!(stdout.contains("Word not in dictionary: XXXXX"));
+        assert!(stdout.contains("Congratulations you won!"));
+    }
+}